@@ -0,0 +1,256 @@
+use std::ops::*;
+use crate::*;
+
+/// An axis-aligned bounding box, stored as its minimum and maximum corners.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Aabb<T, F = Unframed> {
+    pub min: Vector<T, F>,
+    pub max: Vector<T, F>,
+}
+
+impl<T: Unit, F: Frame> Aabb<T, F> {
+    /// The smallest box containing both corners, in any order.
+    #[inline]
+    pub fn new(a: Vector<T, F>, b: Vector<T, F>) -> Self {
+        Aabb {
+            min: Vector::from((a.x.min(b.x), a.y.min(b.y))),
+            max: Vector::from((a.x.max(b.x), a.y.max(b.y))),
+        }
+    }
+
+    #[inline]
+    pub fn from_center(center: Vector<T, F>, half_size: Vector<T, F>) -> Self {
+        Self::new(center - half_size, center + half_size)
+    }
+
+    /// The smallest box containing every point, or `None` if there are none.
+    pub fn from_points<I: IntoIterator<Item=Vector<T, F>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |aabb, point| aabb.including(point)))
+    }
+
+    /// The smallest box containing both `self` and `point`.
+    #[inline]
+    pub fn including(&self, point: Vector<T, F>) -> Self {
+        self.union(&Self::new(point, point))
+    }
+
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Aabb {
+            min: Vector::from((self.min.x.min(other.min.x), self.min.y.min(other.min.y))),
+            max: Vector::from((self.max.x.max(other.max.x), self.max.y.max(other.max.y))),
+        }
+    }
+
+    /// The overlapping region, or `None` if the boxes do not touch.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(Aabb {
+            min: Vector::from((self.min.x.max(other.min.x), self.min.y.max(other.min.y))),
+            max: Vector::from((self.max.x.min(other.max.x), self.max.y.min(other.max.y))),
+        })
+    }
+
+    /// Whether the boxes overlap, counting shared edges.
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Whether the point lies inside the box or on its boundary.
+    #[inline]
+    pub fn contains(&self, point: Vector<T, F>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+
+    #[inline]
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The point in or on the box closest to `point`.
+    #[inline]
+    pub fn closest_point(&self, point: Vector<T, F>) -> Vector<T, F> {
+        Vector::from((
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+        ))
+    }
+
+    /// The distance from `point` to the box, zero inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector<T, F>) -> Scalar<T> {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Grows the box by `margin` on every side. A negative margin shrinks it.
+    #[inline]
+    pub fn expand(&self, margin: Scalar<T>) -> Self {
+        let margin = Vector::from((margin, margin));
+        Aabb { min: self.min - margin, max: self.max + margin }
+    }
+
+    #[inline]
+    pub fn center(&self) -> Vector<T, F> {
+        (self.min + self.max) / 2.0
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vector<T, F> {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn width(&self) -> Scalar<T> {
+        self.max.x - self.min.x
+    }
+
+    #[inline]
+    pub fn height(&self) -> Scalar<T> {
+        self.max.y - self.min.y
+    }
+
+    #[inline]
+    pub fn area<A>(&self) -> Scalar<A>
+        where Scalar<T>: Mul<Output=Scalar<A>>
+    {
+        self.width() * self.height()
+    }
+
+    /// The corners, counter-clockwise from the minimum.
+    #[inline]
+    pub fn corners(&self) -> [Vector<T, F>; 4] {
+        [
+            self.min,
+            Vector::from((self.max.x, self.min.y)),
+            self.max,
+            Vector::from((self.min.x, self.max.y)),
+        ]
+    }
+}
+
+impl<F: Frame> Aabb<Pixels, F> {
+    /// The integer pixel bounds that fully cover the box, as `(min, max)`.
+    #[inline]
+    pub fn to_pixel_bounds(&self) -> (Pixel, Pixel) {
+        (
+            Pixel::from_resolution(self.min.into_frame(), Rounding::Floor),
+            Pixel::from_resolution(self.max.into_frame(), Rounding::Ceil),
+        )
+    }
+}
+
+impl<F: Frame> Div<Scale> for Aabb<Meters, F> {
+    type Output = Aabb<Pixels, F>;
+    #[inline]
+    fn div(self, rhs: Scale) -> Self::Output {
+        Aabb { min: self.min / rhs, max: self.max / rhs }
+    }
+}
+
+impl<F: Frame> Mul<Scale> for Aabb<Pixels, F> {
+    type Output = Aabb<Meters, F>;
+    #[inline]
+    fn mul(self, rhs: Scale) -> Self::Output {
+        Aabb { min: self.min * rhs, max: self.max * rhs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aabb(x0: Float, y0: Float, x1: Float, y1: Float) -> Aabb<Meters> {
+        Aabb::new(Position::in_meters(x0, y0), Position::in_meters(x1, y1))
+    }
+
+    #[test]
+    fn new_orders_corners() {
+        let aabb = aabb(3.0, 1.0, 1.0, 4.0);
+
+        assert_eq!(Position::in_meters(1.0, 1.0), aabb.min);
+        assert_eq!(Position::in_meters(3.0, 4.0), aabb.max);
+    }
+
+    #[test]
+    fn from_points() {
+        let points = vec![
+            Position::in_meters(1.0, 5.0),
+            Position::in_meters(-2.0, 3.0),
+            Position::in_meters(4.0, -1.0),
+        ];
+
+        assert_eq!(Some(aabb(-2.0, -1.0, 4.0, 5.0)), Aabb::from_points(points));
+        assert_eq!(None, Aabb::<Meters>::from_points(vec![]));
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let a = aabb(0.0, 0.0, 2.0, 2.0);
+        let b = aabb(1.0, 1.0, 3.0, 4.0);
+        let c = aabb(5.0, 5.0, 6.0, 6.0);
+
+        assert_eq!(aabb(0.0, 0.0, 3.0, 4.0), a.union(&b));
+        assert_eq!(Some(aabb(1.0, 1.0, 2.0, 2.0)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn containment() {
+        let a = aabb(0.0, 0.0, 4.0, 4.0);
+
+        assert!(a.contains(Position::in_meters(2.0, 4.0)));
+        assert!(!a.contains(Position::in_meters(2.0, 4.5)));
+        assert!(a.contains_aabb(&aabb(1.0, 1.0, 3.0, 3.0)));
+        assert!(!a.contains_aabb(&aabb(1.0, 1.0, 5.0, 3.0)));
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let a = aabb(0.0, 0.0, 4.0, 4.0);
+
+        assert_eq!(Position::in_meters(4.0, 1.0), a.closest_point(Position::in_meters(7.0, 1.0)));
+        assert_eq!(Length::in_meters(5.0), a.distance_to_point(Position::in_meters(-3.0, -4.0)));
+        assert_eq!(Length::zero(), a.distance_to_point(Position::in_meters(2.0, 3.0)));
+    }
+
+    #[test]
+    fn expand() {
+        let a = aabb(0.0, 0.0, 2.0, 2.0);
+
+        assert_eq!(aabb(-1.0, -1.0, 3.0, 3.0), a.expand(Length::in_meters(1.0)));
+    }
+
+    #[test]
+    fn center_size_and_area() {
+        let a = aabb(1.0, 2.0, 4.0, 6.0);
+
+        assert_eq!(Position::in_meters(2.5, 4.0), a.center());
+        assert_eq!(Position::in_meters(3.0, 4.0), a.size());
+        assert_eq!(Area::in_meters_squared(12.0), a.area());
+    }
+
+    #[test]
+    fn conversion_to_pixels() {
+        let a = aabb(0.25, 0.5, 2.0, 3.2);
+        let pixels = a / Scale::from(0.5);
+
+        assert_eq!(Resolution::in_pixels(0.5, 1.0), pixels.min);
+        assert_eq!((Pixel::new(0, 1), Pixel::new(4, 7)), pixels.to_pixel_bounds());
+        assert_eq!(a, pixels * Scale::from(0.5));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::*;
+
+/// The specific gas constant of dry air.
+pub const DRY_AIR_GAS_CONSTANT: Scalar<JoulesPerKilogramKelvin> = Scalar::new(287.052_87);
+
+/// The base altitude in m, base temperature in K and lapse rate in K/m of each ISA layer.
+const STANDARD_LAYERS: [(Float, Float, Float); 8] = [
+    (0.0, 288.15, -0.0065),
+    (11_000.0, 216.65, 0.0),
+    (20_000.0, 216.65, 0.001),
+    (32_000.0, 228.65, 0.0028),
+    (47_000.0, 270.65, 0.0),
+    (51_000.0, 270.65, -0.0028),
+    (71_000.0, 214.65, -0.002),
+    (84_852.0, 186.946, 0.0),
+];
+
+const STANDARD_PRESSURE: Float = 101_325.0;
+
+/// The state of the air at some altitude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AtmosphereSample {
+    pub temperature: Temperature,
+    pub pressure: Pressure,
+    pub density: Density,
+}
+
+/// A model of how the air thins with altitude above the surface.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Atmosphere {
+    /// The International Standard Atmosphere, treating altitude as geopotential. The lowest layer
+    /// extends below sea level, and above the standard's top at 84.852 km the air is taken to be
+    /// isothermal so that pressure and density keep falling exponentially.
+    Standard,
+    /// An isothermal atmosphere whose pressure and density fall by a factor of e every scale height.
+    Exponential {
+        surface_pressure: Pressure,
+        surface_density: Density,
+        temperature: Temperature,
+        scale_height: Length,
+    },
+}
+
+impl Atmosphere {
+    #[inline]
+    pub fn mars() -> Self {
+        Atmosphere::Exponential {
+            surface_pressure: Pressure::in_pascals(610.0),
+            surface_density: Density::in_kilograms_per_meter_cubed(0.020),
+            temperature: Temperature::in_kelvin(210.0),
+            scale_height: Length::in_meters(11_100.0),
+        }
+    }
+
+    #[inline]
+    pub fn venus() -> Self {
+        Atmosphere::Exponential {
+            surface_pressure: Pressure::in_pascals(9.2e6),
+            surface_density: Density::in_kilograms_per_meter_cubed(65.0),
+            temperature: Temperature::in_kelvin(737.0),
+            scale_height: Length::in_meters(15_900.0),
+        }
+    }
+
+    #[inline]
+    pub fn titan() -> Self {
+        Atmosphere::Exponential {
+            surface_pressure: Pressure::in_pascals(146_700.0),
+            surface_density: Density::in_kilograms_per_meter_cubed(5.3),
+            temperature: Temperature::in_kelvin(94.0),
+            scale_height: Length::in_meters(21_000.0),
+        }
+    }
+
+    pub fn sample(&self, altitude: Length) -> AtmosphereSample {
+        match *self {
+            Atmosphere::Standard => standard_atmosphere(altitude),
+            Atmosphere::Exponential { surface_pressure, surface_density, temperature, scale_height } => {
+                let falloff = (-(altitude / scale_height)).exp();
+
+                AtmosphereSample {
+                    temperature,
+                    pressure: surface_pressure * falloff,
+                    density: surface_density * falloff,
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn temperature(&self, altitude: Length) -> Temperature {
+        self.sample(altitude).temperature
+    }
+
+    #[inline]
+    pub fn pressure(&self, altitude: Length) -> Pressure {
+        self.sample(altitude).pressure
+    }
+
+    #[inline]
+    pub fn density(&self, altitude: Length) -> Density {
+        self.sample(altitude).density
+    }
+}
+
+fn standard_atmosphere(altitude: Length) -> AtmosphereSample {
+    let h = altitude.value;
+    let exponent = STANDARD_GRAVITY.value / DRY_AIR_GAS_CONSTANT.value;
+    let mut pressure = STANDARD_PRESSURE;
+
+    let mut layers = STANDARD_LAYERS.iter().peekable();
+    while let Some(&(base, base_temperature, lapse)) = layers.next() {
+        // stop in the layer containing the altitude, or pass through with the pressure at its top
+        let top = layers.peek().map(|&&(top, _, _)| top).filter(|&top| h > top);
+        let height = top.unwrap_or(h) - base;
+        let temperature = base_temperature + lapse * height;
+
+        pressure *= if lapse == 0.0 {
+            (-exponent * height / base_temperature).exp()
+        } else {
+            (temperature / base_temperature).powf(-exponent / lapse)
+        };
+
+        if top.is_none() {
+            let temperature = Temperature::in_kelvin(temperature);
+            let pressure = Pressure::in_pascals(pressure);
+
+            return AtmosphereSample {
+                temperature,
+                pressure,
+                density: pressure / (DRY_AIR_GAS_CONSTANT * temperature),
+            };
+        }
+    }
+
+    unreachable!("the last layer has no top")
+}
+
+/// The pressure of air at `density` stopping against a body moving at `speed`.
+#[inline]
+pub fn dynamic_pressure(density: Density, speed: Speed) -> Pressure {
+    density * (speed * speed) * 0.5
+}
+
+/// The force resisting motion through air at `density` with an airspeed of `velocity`.
+#[inline]
+pub fn drag_force<F: Frame>(velocity: Vector<MetersPerSecond, F>, density: Density, area: Area, coefficient: Float) -> Vector<Newtons, F> {
+    match velocity.unit_vector() {
+        Some(direction) => -direction * (dynamic_pressure(density, velocity.magnitude()) * area * coefficient),
+        None => Vector::zero(),
+    }
+}
+
+/// The force perpendicular to the airspeed `velocity`, a quarter turn counter-clockwise from it
+/// for a positive coefficient.
+#[inline]
+pub fn lift_force<F: Frame>(velocity: Vector<MetersPerSecond, F>, density: Density, area: Area, coefficient: Float) -> Vector<Newtons, F> {
+    match velocity.unit_vector() {
+        Some(direction) => direction.perpendicular() * (dynamic_pressure(density, velocity.magnitude()) * area * coefficient),
+        None => Vector::zero(),
+    }
+}
+
+/// The speed at which drag balances the weight of a falling `mass`.
+#[inline]
+pub fn terminal_speed(mass: Mass, gravity: AccelScalar, density: Density, area: Area, drag_coefficient: Float) -> Speed {
+    (mass * gravity * 2.0 / area / (density * drag_coefficient)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn km(value: Float) -> Length {
+        Length::in_meters(value * 1e3)
+    }
+
+    #[test]
+    fn standard_atmosphere_table() {
+        // altitude, temperature, pressure, density from the published tables
+        let table = [
+            (0.0, 288.15, 101_325.0, 1.2250),
+            (1.0, 281.65, 89_874.6, 1.1116),
+            (11.0, 216.65, 22_632.1, 0.36392),
+            (15.0, 216.65, 12_044.6, 0.19367),
+            (20.0, 216.65, 5_474.89, 0.08803),
+            (32.0, 228.65, 868.019, 0.013225),
+            (47.0, 270.65, 110.906, 0.0014275),
+            (51.0, 270.65, 66.9389, 0.00086160),
+            (71.0, 214.65, 3.95642, 0.000064211),
+        ];
+
+        for &(altitude, temperature, pressure, density) in table.iter() {
+            let sample = Atmosphere::Standard.sample(km(altitude));
+
+            assert_approx_eq!(temperature, sample.temperature.value);
+            assert_approx_eq!(pressure, sample.pressure.value, 1e-4);
+            assert_approx_eq!(density, sample.density.value, 1e-3);
+        }
+    }
+
+    #[test]
+    fn standard_atmosphere_outside_its_range() {
+        let below = Atmosphere::Standard.sample(Length::in_meters(-500.0));
+        assert!(below.temperature > Temperature::in_kelvin(288.15));
+        assert!(below.pressure > Pressure::in_pascals(STANDARD_PRESSURE));
+
+        let high = Atmosphere::Standard.density(km(100.0));
+        assert!(high > Density::zero());
+        assert!(high < Atmosphere::Standard.density(km(84.852)));
+
+        for &altitude in &[200.0, 400.0, 1000.0] {
+            let sample = Atmosphere::Standard.sample(km(altitude));
+
+            assert_approx_eq!(186.946, sample.temperature.value);
+            assert!(sample.density.value.is_finite() && sample.density > Density::zero(), "{:?}", sample);
+            assert!(sample.pressure.value.is_finite() && sample.pressure > Pressure::zero(), "{:?}", sample);
+        }
+    }
+
+    #[test]
+    fn exponential_atmosphere() {
+        let mars = Atmosphere::mars();
+        let sample = mars.sample(Length::in_meters(11_100.0));
+
+        assert_approx_eq!(0.020 / std::f64::consts::E, sample.density.value);
+        assert_approx_eq!(610.0 / std::f64::consts::E, sample.pressure.value);
+        assert_eq!(Temperature::in_kelvin(210.0), sample.temperature);
+        assert!(Atmosphere::venus().density(km(50.0)) > Atmosphere::titan().density(km(50.0)));
+    }
+
+    #[test]
+    fn drag_and_lift() {
+        let velocity = Velocity::in_meters_per_second(30.0, 40.0);
+        let density = Density::in_kilograms_per_meter_cubed(1.2);
+        let area = Area::in_meters_squared(2.0);
+
+        // q = 0.5 * 1.2 * 2500 = 1500 Pa
+        assert_eq!(Pressure::in_pascals(1500.0), dynamic_pressure(density, velocity.magnitude()));
+
+        let drag = drag_force(velocity, density, area, 0.5);
+        assert_approx_eq!(-900.0, drag.x.value);
+        assert_approx_eq!(-1200.0, drag.y.value);
+
+        let lift = lift_force(velocity, density, area, 1.0);
+        assert_approx_eq!(-2400.0, lift.x.value);
+        assert_approx_eq!(1800.0, lift.y.value);
+        assert_approx_eq!(0.0, lift.x.value * velocity.x.value + lift.y.value * velocity.y.value);
+
+        assert_eq!(ForceVector::zero(), drag_force(Velocity::zero(), density, area, 0.5));
+    }
+
+    #[test]
+    fn terminal_speed_balances_weight() {
+        let mass = Mass::in_kilograms(80.0);
+        let gravity = STANDARD_GRAVITY;
+        let density = Atmosphere::Standard.density(Length::zero());
+        let area = Area::in_meters_squared(0.7);
+
+        let speed = terminal_speed(mass, gravity, density, area, 1.0);
+        let drag = drag_force(Velocity::from((Speed::zero(), -speed)), density, area, 1.0);
+
+        assert_approx_eq!((mass * gravity).value, drag.y.value);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::*;
+
+/// A launch that reaches its target.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BallisticSolution<F = Unframed> {
+    pub velocity: Vector<MetersPerSecond, F>,
+    /// The angle of the launch above the horizontal, which is perpendicular to gravity.
+    pub elevation: Angle,
+    pub time_of_flight: Time,
+    /// The greatest height reached above the launch point, against gravity.
+    pub apex_height: Length,
+    pub impact: Vector<Meters, F>,
+}
+
+/// The low and high arcs that carry a projectile fired at `speed` from `launch` to `target`,
+/// or `None` if the target is out of range. The two arcs are the same at maximum range.
+pub fn solve_ballistic<F: Frame>(
+    launch: Vector<Meters, F>,
+    target: Vector<Meters, F>,
+    speed: Speed,
+    gravity: Vector<MetersPerSecondSquared, F>,
+) -> Option<(BallisticSolution<F>, BallisticSolution<F>)> {
+    let times = intercept_times(target - launch, Vector::zero(), -gravity, speed);
+    let low = *times.first()?;
+    let high = *times.last()?;
+
+    let solution = |time: Time| {
+        let velocity = (target - launch) / time - gravity * time * 0.5;
+        BallisticSolution::new(velocity, gravity, Scalar::zero(), time, target)
+    };
+
+    Some((solution(low), solution(high)))
+}
+
+/// The low and high arcs to `target` for a projectile slowed by drag proportional to its
+/// velocity, where `drag` is the drag force per unit mass and speed.
+pub fn solve_ballistic_with_drag<F: Frame>(
+    launch: Vector<Meters, F>,
+    target: Vector<Meters, F>,
+    speed: Speed,
+    gravity: Vector<MetersPerSecondSquared, F>,
+    drag: Scalar<PerSecond>,
+) -> Option<(BallisticSolution<F>, BallisticSolution<F>)> {
+    if drag <= Scalar::zero() {
+        return solve_ballistic(launch, target, speed, gravity);
+    }
+
+    let offset = target - launch;
+    let k = drag.value;
+
+    // the launch velocity that reaches the target after `time`
+    let velocity = |time: Float| -> Vector<MetersPerSecond, F> {
+        let spread = k / -(-k * time).exp_m1();
+        let component = |d: Float, g: Float| (d - g * time / k) * spread + g / k;
+
+        Vector::from((
+            component(offset.x.value, gravity.x.value),
+            component(offset.y.value, gravity.y.value),
+        ))
+    };
+    let excess = |time: Float| velocity(time).magnitude().value.powi(2) - speed.value.powi(2);
+
+    // the speed needed is large for very short and very long flights, so look for the two
+    // crossings on a logarithmic scan of flight times
+    let reach = offset.magnitude().value / speed.value;
+    let fall = speed.value / gravity.magnitude().value.max(Float::MIN_POSITIVE);
+
+    // a target at the launch point can only be hit by firing straight up and falling back
+    if reach == 0.0 && gravity == Vector::zero() {
+        return None;
+    }
+
+    let shortest = if reach > 0.0 { reach } else { fall } * 1e-3;
+    let longest = 1e3 * (reach + fall);
+    let samples = 400;
+    let ratio = (longest / shortest).powf(1.0 / samples as Float);
+
+    let mut roots = vec![];
+    let mut previous = shortest;
+    for i in 1..=samples {
+        let next = shortest * ratio.powi(i);
+        if (excess(previous) > 0.0) != (excess(next) > 0.0) {
+            roots.push(bisect(&excess, previous, next));
+        }
+        previous = next;
+    }
+
+    let solution = |time: Float| {
+        BallisticSolution::new(velocity(time), gravity, drag, Time::in_seconds(time), target)
+    };
+
+    Some((solution(*roots.first()?), solution(*roots.last()?)))
+}
+
+/// The earliest launch from `launch` at `speed` that hits a target moving from `target` at a
+/// constant `target_velocity`, or `None` if the projectile can never reach it.
+pub fn solve_ballistic_intercept<F: Frame>(
+    launch: Vector<Meters, F>,
+    target: Vector<Meters, F>,
+    target_velocity: Vector<MetersPerSecond, F>,
+    speed: Speed,
+    gravity: Vector<MetersPerSecondSquared, F>,
+) -> Option<BallisticSolution<F>> {
+    let time = *intercept_times(target - launch, target_velocity, -gravity, speed).first()?;
+    let impact = target + target_velocity * time;
+    let velocity = (impact - launch) / time - gravity * time * 0.5;
+
+    Some(BallisticSolution::new(velocity, gravity, Scalar::zero(), time, impact))
+}
+
+impl<F: Frame> BallisticSolution<F> {
+    fn new(
+        velocity: Vector<MetersPerSecond, F>,
+        gravity: Vector<MetersPerSecondSquared, F>,
+        drag: Scalar<PerSecond>,
+        time_of_flight: Time,
+        impact: Vector<Meters, F>,
+    ) -> Self {
+        let up = (-gravity).unit_vector().unwrap_or_else(|| Vector::from((0.0, 1.0)));
+        let g = gravity.magnitude().value;
+        let k = drag.value;
+        let rising = up.dot(velocity).value;
+
+        let apex_height = if rising <= 0.0 || g == 0.0 {
+            0.0
+        } else if k == 0.0 {
+            rising * rising / (2.0 * g)
+        } else {
+            rising / k - g * (k * rising / g).ln_1p() / (k * k)
+        };
+
+        BallisticSolution {
+            velocity,
+            elevation: Angle::in_radians((rising / velocity.magnitude().value).clamp(-1.0, 1.0).asin()),
+            time_of_flight,
+            apex_height: Length::in_meters(apex_height),
+            impact,
+        }
+    }
+}
+
+/// The positive times, in increasing order, when a projectile leaving the origin at `speed` in
+/// any direction can meet a target at `offset` moving with `velocity`, where `acceleration` is
+/// that of the target relative to the projectile.
+pub(crate) fn intercept_times<F: Frame>(
+    offset: Vector<Meters, F>,
+    velocity: Vector<MetersPerSecond, F>,
+    acceleration: Vector<MetersPerSecondSquared, F>,
+    speed: Speed,
+) -> Vec<Time> {
+    // |d + v t + a t² / 2|² = s² t²
+    let (d, v, a) = (offset, velocity, acceleration * 0.5);
+    let coefficients = [
+        d.magnitude_squared(),
+        (d.dot(v) * 2.0).value,
+        (v.dot(v) + a.dot(d) * 2.0 - speed * speed).value,
+        (a.dot(v) * 2.0).value,
+        a.magnitude_squared(),
+    ];
+
+    polynomial_roots(&coefficients)
+        .into_iter()
+        .filter(|&t| t > 0.0)
+        .map(Time::in_seconds)
+        .collect()
+}
+
+/// The real roots in increasing order of the polynomial with `coefficients` from the constant term up,
+/// or none if any coefficient is not finite.
+fn polynomial_roots(coefficients: &[Float]) -> Vec<Float> {
+    if coefficients.iter().any(|c| !c.is_finite()) {
+        return vec![];
+    }
+
+    let degree = match coefficients.iter().rposition(|&c| c != 0.0) {
+        Some(degree) => degree,
+        None => return vec![],
+    };
+    let coefficients = &coefficients[..=degree];
+
+    if degree == 0 {
+        return vec![];
+    }
+
+    let evaluate = |x: Float| coefficients.iter().rev().fold(0.0, |sum, &c| sum * x + c);
+
+    // the polynomial is monotonic between the roots of its derivative
+    let derivative: Vec<Float> = coefficients.iter().enumerate().skip(1).map(|(i, &c)| c * i as Float).collect();
+    let bound = 1.0 + coefficients[..degree].iter().map(|c| (c / coefficients[degree]).abs()).fold(0.0, Float::max);
+
+    let mut edges = vec![-bound];
+    edges.extend(polynomial_roots(&derivative).into_iter().filter(|x| x.abs() < bound));
+    edges.push(bound);
+
+    let mut roots: Vec<Float> = vec![];
+    for pair in edges.windows(2) {
+        let (low, high) = (pair[0], pair[1]);
+        if evaluate(low) == 0.0 {
+            roots.push(low);
+        } else if evaluate(high) != 0.0 && (evaluate(low) > 0.0) != (evaluate(high) > 0.0) {
+            roots.push(bisect(&evaluate, low, high));
+        }
+    }
+
+    // a double root touches zero at a turning point without crossing it
+    for &turn in &edges[1..edges.len() - 1] {
+        let scale = coefficients.iter().enumerate().map(|(i, c)| (c * turn.abs().powi(i as i32)).abs()).fold(0.0, Float::max);
+        if evaluate(turn).abs() <= 1e-12 * scale && !roots.iter().any(|&r| (r - turn).abs() <= 1e-9 * turn.abs().max(1.0)) {
+            roots.push(turn);
+        }
+    }
+
+    roots.sort_by(Float::total_cmp);
+    roots
+}
+
+/// Narrows a sign change of `function` between `low` and `high` down to adjacent floats.
+fn bisect<G: Fn(Float) -> Float>(function: &G, mut low: Float, mut high: Float) -> Float {
+    let low_positive = function(low) > 0.0;
+
+    loop {
+        let middle = low + (high - low) / 2.0;
+        if middle <= low || middle >= high {
+            return middle;
+        }
+
+        if (function(middle) > 0.0) == low_positive {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gravity() -> Acceleration {
+        Acceleration::in_meters_per_second_squared(0.0, -9.8)
+    }
+
+    fn position_at(launch: Position, velocity: Velocity, time: Time) -> Position {
+        launch + velocity * time + gravity() * time * time * 0.5
+    }
+
+    fn assert_hits(launch: Position, solution: &BallisticSolution, speed: Speed) {
+        let end = position_at(launch, solution.velocity, solution.time_of_flight);
+
+        assert!((end - solution.impact).magnitude().value < 1e-6, "landed at {:?}", end);
+        assert_approx_eq!(speed.value, solution.velocity.magnitude().value);
+    }
+
+    #[test]
+    fn level_ground() {
+        let speed = Speed::in_meters_per_second(50.0);
+        let target = Position::in_meters(200.0, 0.0);
+        let (low, high) = solve_ballistic(Position::zero(), target, speed, gravity()).unwrap();
+
+        // the range equation, R = v² sin 2θ / g
+        let angle = 0.5 * (200.0 * 9.8 / 2500.0_f64).asin();
+        assert_approx_eq!(angle, low.elevation.value);
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2 - angle, high.elevation.value);
+
+        assert_approx_eq!(2.0 * 50.0 * angle.sin() / 9.8, low.time_of_flight.value);
+        assert_approx_eq!((50.0 * (high.elevation.value).sin()).powi(2) / 19.6, high.apex_height.value);
+
+        assert_hits(Position::zero(), &low, speed);
+        assert_hits(Position::zero(), &high, speed);
+    }
+
+    #[test]
+    fn raised_targets_and_range() {
+        let launch = Position::in_meters(10.0, 5.0);
+        let speed = Speed::in_meters_per_second(30.0);
+
+        let target = Position::in_meters(-40.0, 25.0);
+        let (low, high) = solve_ballistic(launch, target, speed, gravity()).unwrap();
+        assert!(low.time_of_flight < high.time_of_flight);
+        assert!(low.velocity.x.value < 0.0);
+        assert_hits(launch, &low, speed);
+        assert_hits(launch, &high, speed);
+
+        // the maximum range on level ground is v² / g, with both arcs at 45 degrees
+        let (low, high) = solve_ballistic(Position::zero(), Position::in_meters(900.0 / 9.8, 0.0), speed, gravity()).unwrap();
+        assert_approx_eq!(low.elevation.value, high.elevation.value, 1e-6);
+        assert_approx_eq!(std::f64::consts::FRAC_PI_4, low.elevation.value, 1e-6);
+
+        assert_eq!(None, solve_ballistic(Position::zero(), Position::in_meters(95.0, 0.0), speed, gravity()));
+    }
+
+    #[test]
+    fn no_gravity_flies_straight() {
+        let speed = Speed::in_meters_per_second(10.0);
+        let (low, high) = solve_ballistic(Position::zero(), Position::in_meters(30.0, 40.0), speed, Acceleration::zero()).unwrap();
+
+        assert_eq!(low, high);
+        assert_approx_eq!(5.0, low.time_of_flight.value);
+        assert_approx_eq!(6.0, low.velocity.x.value);
+    }
+
+    #[test]
+    fn linear_drag() {
+        let speed = Speed::in_meters_per_second(50.0);
+        let target = Position::in_meters(150.0, 10.0);
+        let drag = Scalar::<PerSecond>::from(0.1);
+
+        let (low, high) = solve_ballistic_with_drag(Position::zero(), target, speed, gravity(), drag).unwrap();
+        let (plain, _) = solve_ballistic(Position::zero(), target, speed, gravity()).unwrap();
+
+        // integrating the drag equations lands on the target
+        for solution in [low, high].iter() {
+            let acceleration = |_: Time, state: &State| gravity() - state.velocity / Time::in_seconds(10.0);
+            let steps = 2000;
+            let dt = solution.time_of_flight / steps as Float;
+            let end = Integrator::RungeKutta4.integrate(State::new(Position::zero(), solution.velocity), Time::zero(), dt, steps, acceleration);
+
+            assert!((end.position - target).magnitude().value < 1e-6, "landed at {:?}", end.position);
+            assert_approx_eq!(speed.value, solution.velocity.magnitude().value, 1e-6);
+        }
+
+        assert!(low.elevation > plain.elevation);
+        assert!(low.time_of_flight > plain.time_of_flight);
+        assert!(solve_ballistic_with_drag(Position::zero(), Position::in_meters(240.0, 0.0), speed, gravity(), drag).is_none());
+
+        // a target at the launch point is hit by firing straight up, falling back sooner than without drag
+        let (up, _) = solve_ballistic_with_drag(target, target, speed, gravity(), drag).unwrap();
+        let (plain_up, _) = solve_ballistic(target, target, speed, gravity()).unwrap();
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, up.elevation.value, 1e-6);
+        assert_approx_eq!(std::f64::consts::FRAC_PI_2, plain_up.elevation.value, 1e-6);
+        assert!(up.time_of_flight < plain_up.time_of_flight);
+        assert_eq!(None, solve_ballistic_with_drag(target, target, speed, Acceleration::zero(), drag));
+    }
+
+    #[test]
+    fn moving_target() {
+        let launch = Position::zero();
+        let target = Position::in_meters(100.0, 0.0);
+        let target_velocity = Velocity::in_meters_per_second(-5.0, 2.0);
+        let speed = Speed::in_meters_per_second(40.0);
+
+        let solution = solve_ballistic_intercept(launch, target, target_velocity, speed, gravity()).unwrap();
+        let meeting = target + target_velocity * solution.time_of_flight;
+
+        assert!((meeting - solution.impact).magnitude().value < 1e-9);
+        assert_hits(launch, &solution, speed);
+
+        // a target running away faster than the projectile can reach
+        let fleeing = Velocity::in_meters_per_second(50.0, 0.0);
+        assert_eq!(None, solve_ballistic_intercept(launch, target, fleeing, speed, gravity()));
+    }
+
+    #[test]
+    fn quartic_roots() {
+        // (x - 1)(x - 2)(x + 3)(x - 5) = x⁴ - 5x³ - 7x² + 41x - 30
+        let roots = polynomial_roots(&[-30.0, 41.0, -7.0, -5.0, 1.0]);
+        let expected = [-3.0, 1.0, 2.0, 5.0];
+
+        assert_eq!(4, roots.len());
+        for (root, expected) in roots.iter().zip(expected.iter()) {
+            assert_approx_eq!(*expected, *root);
+        }
+
+        assert_eq!(vec![2.0], polynomial_roots(&[4.0, -4.0, 1.0]));
+        assert!(polynomial_roots(&[1.0, 0.0, 1.0]).is_empty());
+
+        // coefficients that are not finite have no meaningful roots
+        assert!(polynomial_roots(&[Float::NAN, 2.0, -3.0, 0.5, 1.0]).is_empty());
+        assert!(polynomial_roots(&[-1.0, Float::INFINITY, 0.0, 1.0]).is_empty());
+
+        let speed = Speed::in_meters_per_second(50.0);
+        assert_eq!(None, solve_ballistic(Position::zero(), Position::in_meters(Float::NAN, 10.0), speed, gravity()));
+    }
+}
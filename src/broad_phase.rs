@@ -0,0 +1,209 @@
+use crate::*;
+
+/// A sort-and-sweep broad phase over bounding boxes.
+///
+/// Boxes are kept sorted by their minimum x between steps. Bodies rarely pass each other in a
+/// single step, so re-sorting with an insertion sort costs little more than a pass over the list.
+/// Pairs are reported in ascending order of handle, independent of insertion history, so every
+/// peer running the same simulation sees the same pairs in the same order.
+#[derive(Debug, Clone)]
+pub struct SortAndSweep<F = Unframed> {
+    boxes: Vec<Option<Aabb<Meters, F>>>,
+    free: Vec<usize>,
+    order: Vec<SpatialId>,
+}
+
+impl<F: Frame> Default for SortAndSweep<F> {
+    #[inline]
+    fn default() -> Self {
+        SortAndSweep { boxes: vec![], free: vec![], order: vec![] }
+    }
+}
+
+impl<F: Frame> SortAndSweep<F> {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, id: SpatialId) -> Option<Aabb<Meters, F>> {
+        *self.boxes.get(id.0)?
+    }
+
+    pub fn insert(&mut self, aabb: Aabb<Meters, F>) -> SpatialId {
+        let id = match self.free.pop() {
+            Some(index) => {
+                self.boxes[index] = Some(aabb);
+                SpatialId(index)
+            }
+            None => {
+                self.boxes.push(Some(aabb));
+                SpatialId(self.boxes.len() - 1)
+            }
+        };
+
+        self.order.push(id);
+        id
+    }
+
+    pub fn remove(&mut self, id: SpatialId) -> Option<Aabb<Meters, F>> {
+        let aabb = self.boxes.get_mut(id.0)?.take()?;
+
+        self.free.push(id.0);
+        self.order.retain(|&other| other != id);
+
+        Some(aabb)
+    }
+
+    /// Replaces the box of a body that has moved, returning `false` if it does not exist.
+    pub fn update(&mut self, id: SpatialId, aabb: Aabb<Meters, F>) -> bool {
+        match self.boxes.get_mut(id.0) {
+            Some(Some(current)) => {
+                *current = aabb;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Every pair of overlapping boxes as `(lower, higher)` handles, in ascending order.
+    ///
+    /// Boxes that share only an edge count as overlapping.
+    pub fn pairs(&mut self) -> Vec<(SpatialId, SpatialId)> {
+        self.sort();
+
+        let mut pairs = vec![];
+        let mut active: Vec<(SpatialId, Aabb<Meters, F>)> = vec![];
+
+        for &id in &self.order {
+            let aabb = self.boxes[id.0].unwrap();
+
+            active.retain(|(_, other)| other.max.x >= aabb.min.x);
+
+            for &(other_id, other) in &active {
+                if aabb.intersects(&other) {
+                    pairs.push((id.min(other_id), id.max(other_id)));
+                }
+            }
+
+            active.push((id, aabb));
+        }
+
+        pairs.sort();
+        pairs
+    }
+
+    /// Insertion sort by minimum x, with ties broken by handle so the order is repeatable.
+    fn sort(&mut self) {
+        let boxes = &self.boxes;
+        let key = |id: SpatialId| (boxes[id.0].unwrap().min.x, id);
+
+        for i in 1..self.order.len() {
+            let mut j = i;
+
+            while j > 0 && key(self.order[j]) < key(self.order[j - 1]) {
+                self.order.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aabb(x0: Float, y0: Float, x1: Float, y1: Float) -> Aabb<Meters> {
+        Aabb::new(Position::in_meters(x0, y0), Position::in_meters(x1, y1))
+    }
+
+    fn brute_force(boxes: &[Aabb<Meters>]) -> Vec<(SpatialId, SpatialId)> {
+        let mut pairs = vec![];
+
+        for i in 0..boxes.len() {
+            for j in i + 1..boxes.len() {
+                if boxes[i].intersects(&boxes[j]) {
+                    pairs.push((SpatialId(i), SpatialId(j)));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    #[test]
+    fn finds_overlapping_pairs() {
+        let mut broad_phase = SortAndSweep::new();
+        let a = broad_phase.insert(aabb(0.0, 0.0, 2.0, 2.0));
+        let b = broad_phase.insert(aabb(1.0, 1.0, 3.0, 3.0));
+        let c = broad_phase.insert(aabb(1.5, 5.0, 2.5, 6.0));
+        let d = broad_phase.insert(aabb(3.0, 0.0, 4.0, 1.0));
+
+        assert_eq!(vec![(a, b), (b, d)], broad_phase.pairs());
+
+        broad_phase.update(c, aabb(1.5, 2.5, 2.5, 3.5));
+        assert_eq!(vec![(a, b), (b, c), (b, d)], broad_phase.pairs());
+
+        assert_eq!(Some(aabb(0.0, 0.0, 2.0, 2.0)), broad_phase.remove(a));
+        assert_eq!(None, broad_phase.remove(a));
+        assert_eq!(vec![(b, c), (b, d)], broad_phase.pairs());
+    }
+
+    #[test]
+    fn matches_brute_force_as_boxes_move() {
+        let mut boxes = (0..60)
+            .map(|i| {
+                let x = (i * 37 % 50) as Float;
+                let y = (i * 11 % 20) as Float;
+                aabb(x, y, x + 3.0, y + 2.0)
+            })
+            .collect::<Vec<_>>();
+
+        let mut broad_phase = SortAndSweep::new();
+        for &bounds in &boxes {
+            broad_phase.insert(bounds);
+        }
+
+        for step in 0..20 {
+            for (i, aabb) in boxes.iter_mut().enumerate() {
+                let dx = if i % 2 == 0 { 0.7 } else { -0.4 } * (step % 5) as Float;
+                let offset = Position::in_meters(dx, 0.0);
+                *aabb = Aabb { min: aabb.min + offset, max: aabb.max + offset };
+                assert!(broad_phase.update(SpatialId(i), *aabb));
+            }
+
+            assert_eq!(brute_force(&boxes), broad_phase.pairs());
+        }
+    }
+
+    #[test]
+    fn output_order_does_not_depend_on_history() {
+        let boxes = [aabb(4.0, 0.0, 6.0, 2.0), aabb(0.0, 0.0, 5.0, 1.0), aabb(1.0, 0.0, 4.0, 3.0)];
+
+        let mut first = SortAndSweep::new();
+        let mut second = SortAndSweep::new();
+        for &bounds in &boxes {
+            first.insert(bounds);
+            second.insert(aabb(10.0, 10.0, 11.0, 11.0));
+        }
+
+        second.pairs();
+        for (i, &bounds) in boxes.iter().enumerate() {
+            second.update(SpatialId(i), bounds);
+        }
+
+        assert_eq!(first.pairs(), second.pairs());
+        assert_eq!(brute_force(&boxes), first.pairs());
+    }
+}
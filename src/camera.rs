@@ -0,0 +1,249 @@
+use crate::*;
+
+/// A 2D camera looking down on the world.
+///
+/// Screen coordinates are in pixels with the origin at the top-left corner of the viewport
+/// and the y-axis pointing down, while world coordinates have the y-axis pointing up.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Camera2 {
+    pub center: Vector<Meters, World>,
+    pub zoom: Scale,
+    pub rotation: Angle,
+    pub viewport: Resolution,
+}
+
+impl Camera2 {
+    #[inline]
+    pub fn new(center: Vector<Meters, World>, zoom: Scale, viewport: Resolution) -> Self {
+        Camera2 { center, zoom, rotation: Angle::zero(), viewport }
+    }
+
+    #[inline]
+    fn half_viewport(&self) -> Resolution {
+        self.viewport / 2.0
+    }
+
+    pub fn world_to_screen(&self, position: Vector<Meters, World>) -> Vector<Pixels, Screen> {
+        let offset = (position - self.center).rotate_cw(-self.rotation) / self.zoom;
+        let half = self.half_viewport();
+
+        Vector::from((half.x + offset.x, half.y - offset.y))
+    }
+
+    pub fn screen_to_world(&self, pixel: Vector<Pixels, Screen>) -> Vector<Meters, World> {
+        let half = self.half_viewport();
+        let offset = Vector::<Pixels, World>::from((pixel.x - half.x, half.y - pixel.y));
+
+        (offset * self.zoom).rotate_cw(self.rotation) + self.center
+    }
+
+    /// The world positions of the viewport corners, clockwise from the top-left.
+    pub fn visible_corners(&self) -> [Vector<Meters, World>; 4] {
+        let zero = ResolutionScalar::zero();
+        let Resolution { x: width, y: height, .. } = self.viewport;
+
+        [
+            self.screen_to_world(Vector::from((zero, zero))),
+            self.screen_to_world(Vector::from((width, zero))),
+            self.screen_to_world(Vector::from((width, height))),
+            self.screen_to_world(Vector::from((zero, height))),
+        ]
+    }
+
+    /// The smallest axis-aligned box containing everything the camera can see.
+    #[inline]
+    pub fn visible_bounds(&self) -> Aabb<Meters, World> {
+        let corners = self.visible_corners();
+        Aabb::new(corners[0], corners[2]).union(&Aabb::new(corners[1], corners[3]))
+    }
+
+    /// Zooms by `factor` while keeping the world position under `cursor` fixed on screen.
+    ///
+    /// Factors greater than one zoom in. Factors that are not positive and finite are ignored.
+    pub fn zoom_about(&mut self, cursor: Vector<Pixels, Screen>, factor: Float) {
+        if !(factor > 0.0 && factor.is_finite()) {
+            return;
+        }
+
+        let before = self.screen_to_world(cursor);
+        self.zoom /= factor;
+        let after = self.screen_to_world(cursor);
+
+        self.center += before - after;
+    }
+
+    #[inline]
+    pub fn pan(&mut self, offset: Vector<Meters, World>) {
+        self.center += offset;
+    }
+
+    #[inline]
+    pub fn animate_to(&self, target: Camera2, duration: Time) -> CameraAnimation {
+        CameraAnimation { start: *self, target, duration, elapsed: Time::zero() }
+    }
+}
+
+/// A smooth pan, zoom and rotation between two camera states.
+///
+/// Zoom is interpolated geometrically so that zooming in and out feel equally fast.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CameraAnimation {
+    pub start: Camera2,
+    pub target: Camera2,
+    pub duration: Time,
+    pub elapsed: Time,
+}
+
+impl CameraAnimation {
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the animation by `dt` and returns the camera at the new time.
+    #[inline]
+    pub fn advance(&mut self, dt: Time) -> Camera2 {
+        self.elapsed += dt;
+        self.sample(self.elapsed)
+    }
+
+    pub fn sample(&self, elapsed: Time) -> Camera2 {
+        if elapsed >= self.duration {
+            return self.target;
+        }
+
+        let t = (elapsed / self.duration).max(0.0);
+        let t = t * t * (3.0 - 2.0 * t);
+
+        let start = &self.start;
+        let target = &self.target;
+
+        Camera2 {
+            center: start.center + (target.center - start.center) * t,
+            zoom: start.zoom * (target.zoom / start.zoom).powf(t),
+            rotation: start.rotation + (target.rotation - start.rotation) * t,
+            viewport: target.viewport,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world(x: Float, y: Float) -> Vector<Meters, World> {
+        Vector::in_meters(x, y)
+    }
+
+    fn screen(x: Float, y: Float) -> Vector<Pixels, Screen> {
+        Vector::in_pixels(x, y)
+    }
+
+    fn camera() -> Camera2 {
+        Camera2::new(world(100.0, 50.0), Scale::from(0.5), Resolution::in_pixels(800.0, 600.0))
+    }
+
+    fn assert_screen_eq(expected: Vector<Pixels, Screen>, actual: Vector<Pixels, Screen>) {
+        assert_approx_eq!(expected.x.value, actual.x.value);
+        assert_approx_eq!(expected.y.value, actual.y.value);
+    }
+
+    fn assert_world_eq(expected: Vector<Meters, World>, actual: Vector<Meters, World>) {
+        assert_approx_eq!(expected.x.value, actual.x.value);
+        assert_approx_eq!(expected.y.value, actual.y.value);
+    }
+
+    #[test]
+    fn center_maps_to_middle_of_viewport() {
+        let camera = camera();
+
+        assert_eq!(screen(400.0, 300.0), camera.world_to_screen(camera.center));
+    }
+
+    #[test]
+    fn screen_y_axis_points_down() {
+        let camera = camera();
+        let above = camera.center + world(10.0, 5.0);
+
+        assert_eq!(screen(420.0, 290.0), camera.world_to_screen(above));
+    }
+
+    #[test]
+    fn round_trip_with_rotation() {
+        let mut camera = camera();
+        camera.rotation = Angle::in_degrees(30.0);
+        let position = world(80.0, 70.0);
+
+        assert_world_eq(position, camera.screen_to_world(camera.world_to_screen(position)));
+    }
+
+    #[test]
+    fn rotation_turns_the_view() {
+        let mut camera = camera();
+        camera.rotation = Angle::in_degrees(90.0);
+        let ahead = camera.center + world(0.0, 10.0).rotate_cw(camera.rotation);
+
+        assert_screen_eq(screen(400.0, 280.0), camera.world_to_screen(ahead));
+    }
+
+    #[test]
+    fn visible_corners() {
+        let corners = camera().visible_corners();
+
+        assert_eq!(world(-100.0, 200.0), corners[0]);
+        assert_eq!(world(300.0, -100.0), corners[2]);
+    }
+
+    #[test]
+    fn visible_bounds_of_rotated_camera() {
+        let mut camera = Camera2::new(Vector::zero(), Scale::from(1.0), Resolution::in_pixels(4.0, 2.0));
+        assert_eq!(Aabb::new(world(-2.0, -1.0), world(2.0, 1.0)), camera.visible_bounds());
+
+        camera.rotation = Angle::in_degrees(90.0);
+        let bounds = camera.visible_bounds();
+        assert_approx_eq!(2.0, bounds.width().value);
+        assert_approx_eq!(4.0, bounds.height().value);
+    }
+
+    #[test]
+    fn zoom_about_keeps_cursor_fixed() {
+        let mut camera = camera();
+        let cursor = screen(600.0, 100.0);
+        let before = camera.screen_to_world(cursor);
+
+        camera.zoom_about(cursor, 2.0);
+
+        assert_eq!(Scale::from(0.25), camera.zoom);
+        assert_world_eq(before, camera.screen_to_world(cursor));
+    }
+
+    #[test]
+    fn zoom_about_ignores_invalid_factors() {
+        let mut zoomed = camera();
+        let cursor = screen(600.0, 100.0);
+
+        for &factor in &[0.0, -1.0, Float::NAN, Float::INFINITY] {
+            zoomed.zoom_about(cursor, factor);
+        }
+
+        assert_eq!(camera(), zoomed);
+    }
+
+    #[test]
+    fn animation_interpolates_between_cameras() {
+        let start = camera();
+        let mut target = start;
+        target.center = world(200.0, 50.0);
+        target.zoom = Scale::from(2.0);
+
+        let mut animation = start.animate_to(target, Time::in_seconds(2.0));
+
+        let halfway = animation.advance(Time::in_seconds(1.0));
+        assert_world_eq(world(150.0, 50.0), halfway.center);
+        assert_approx_eq!(1.0, halfway.zoom.value);
+        assert!(!animation.is_finished());
+
+        assert_eq!(target, animation.advance(Time::in_seconds(1.0)));
+        assert!(animation.is_finished());
+    }
+}
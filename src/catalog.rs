@@ -0,0 +1,318 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+use crate::*;
+
+/// The built-in bodies, in the same format read by [`Catalog::parse`].
+///
+/// Planets orbit the Sun with the longitude of perihelion as the argument of periapsis. Moons
+/// are given with their periapsis along the x-axis.
+const SOLAR_SYSTEM: &str = "\
+# name,     parent,  mass (kg),    radius (m), rotation (s), semi-major axis (m), eccentricity, inclination (°), periapsis (°)
+Sun,        -,       1.98847e30,   6.957e8,    2192832
+Mercury,    Sun,     3.3011e23,    2.4397e6,   5067014,      5.7909e10,  0.2056,   0,    77.46
+Venus,      Sun,     4.8675e24,    6.0518e6,   -20997360,    1.08208e11, 0.0068,   0,    131.53
+Earth,      Sun,     5.9722e24,    6.371e6,    86164.1,      1.49598e11, 0.0167,   0,    102.94
+Mars,       Sun,     6.4171e23,    3.3895e6,   88642.7,      2.27939e11, 0.0934,   0,    336.04
+Jupiter,    Sun,     1.89819e27,   6.9911e7,   35730,        7.78479e11, 0.0489,   0,    14.75
+Saturn,     Sun,     5.6834e26,    5.8232e7,   38018,        1.43353e12, 0.0565,   0,    92.43
+Uranus,     Sun,     8.6813e25,    2.5362e7,   -62064,       2.87246e12, 0.0457,   0,    170.96
+Neptune,    Sun,     1.02413e26,   2.4622e7,   57996,        4.49506e12, 0.0113,   0,    44.97
+Moon,       Earth,   7.342e22,     1.7374e6,   2360591.5,    3.844e8,    0.0549,   0,    0
+Phobos,     Mars,    1.0659e16,    1.1267e4,   27553.8,      9.3772e6,   0.0151,   0,    0
+Deimos,     Mars,    1.4762e15,    6.2e3,      109123.2,     2.34632e7,  0.0002,   0,    0
+Io,         Jupiter, 8.931938e22,  1.8216e6,   152853.5,     4.217e8,    0.0041,   0,    0
+Europa,     Jupiter, 4.799844e22,  1.5608e6,   306822,       6.709e8,    0.009,    0,    0
+Ganymede,   Jupiter, 1.4819e23,    2.6341e6,   618153,       1.0704e9,   0.0013,   0,    0
+Callisto,   Jupiter, 1.075938e23,  2.4103e6,   1441931,      1.8827e9,   0.0074,   0,    0
+Titan,      Saturn,  1.3452e23,    2.5747e6,   1377648,      1.22187e9,  0.0288,   0,    0
+Triton,     Neptune, 2.139e22,     1.3534e6,   507773,       3.54759e8,  0.000016, 180,  0
+";
+
+/// The size and shape of an orbit around a parent body, without its position in time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OrbitalElements {
+    pub semi_major_axis: Length,
+    pub eccentricity: Float,
+    pub inclination: Angle,
+    pub argument_of_periapsis: Angle,
+}
+
+/// A star, planet or moon.
+///
+/// A negative rotation period is a retrograde spin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CelestialBody {
+    pub name: String,
+    pub parent: Option<String>,
+    pub mass: Mass,
+    pub radius: Length,
+    pub rotation_period: Time,
+    pub elements: Option<OrbitalElements>,
+}
+
+impl CelestialBody {
+    #[inline]
+    pub fn gravitational_parameter(&self) -> GravitationalParameter {
+        GRAVITATIONAL_CONSTANT * self.mass
+    }
+
+    #[inline]
+    pub fn surface_gravity(&self) -> AccelScalar {
+        self.gravitational_parameter() / (self.radius * self.radius)
+    }
+}
+
+/// A collection of bodies looked up by name, ignoring case.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Catalog {
+    bodies: Vec<CelestialBody>,
+}
+
+impl Catalog {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The Sun, the planets and their major moons.
+    pub fn solar_system() -> Self {
+        let mut catalog = Self::new();
+        catalog.parse(SOLAR_SYSTEM).expect("built-in catalog is valid");
+        catalog
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&CelestialBody> {
+        self.bodies.iter().find(|body| body.name.eq_ignore_ascii_case(name))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item=&CelestialBody> {
+        self.bodies.iter()
+    }
+
+    /// The bodies directly orbiting `parent`.
+    pub fn children<'a>(&'a self, parent: &'a str) -> impl Iterator<Item=&'a CelestialBody> {
+        self.bodies.iter().filter(move |body| {
+            body.parent.as_ref().is_some_and(|name| name.eq_ignore_ascii_case(parent))
+        })
+    }
+
+    /// Adds a body, replacing any existing body with the same name.
+    pub fn insert(&mut self, body: CelestialBody) {
+        match self.bodies.iter_mut().find(|existing| existing.name.eq_ignore_ascii_case(&body.name)) {
+            Some(existing) => *existing = body,
+            None => self.bodies.push(body),
+        }
+    }
+
+    /// The orbit of `name` around its parent, passing periapsis at time zero.
+    pub fn orbit(&self, name: &str) -> Option<Orbit> {
+        let body = self.get(name)?;
+        let elements = body.elements?;
+        let parent = self.get(body.parent.as_ref()?)?;
+
+        let mut orbit = Orbit::from_periapsis(
+            parent.mass,
+            elements.semi_major_axis * (1.0 - elements.eccentricity),
+            elements.eccentricity,
+            elements.argument_of_periapsis,
+            Time::zero(),
+        );
+        orbit.inclination = elements.inclination;
+        Some(orbit)
+    }
+
+    /// Adds the bodies described by `text`, one per line, replacing any with the same name.
+    ///
+    /// Each line holds comma-separated fields: name, parent (or `-`), mass in kg, radius in m and
+    /// rotation period in s. Bodies with a parent continue with the semi-major axis in m, the
+    /// eccentricity, and the inclination and argument of periapsis in degrees. Blank lines and
+    /// anything after a `#` are ignored. A parent must be already in the catalog or earlier in
+    /// the text. Nothing is added if any line is invalid.
+    pub fn parse(&mut self, text: &str) -> Result<(), ParseCatalogError> {
+        let mut parsed = Catalog::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let error = |reason: &str| ParseCatalogError { line: index + 1, reason: reason.to_string() };
+
+            let content = line.split('#').next().unwrap_or_default().trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = content.split(',').map(str::trim).collect();
+            let number = |i: usize| -> Result<Float, ParseCatalogError> {
+                fields[i].parse().map_err(|_| error(&format!("invalid number '{}'", fields[i])))
+            };
+
+            if fields[0].is_empty() {
+                return Err(error("missing name"));
+            }
+
+            let parent = match fields.get(1) {
+                Some(&"-") => None,
+                Some(name) if self.get(name).is_some() || parsed.get(name).is_some() => Some(name.to_string()),
+                Some(name) => return Err(error(&format!("unknown parent '{}'", name))),
+                None => return Err(error("missing parent")),
+            };
+
+            let expected = if parent.is_some() { 9 } else { 5 };
+            if fields.len() != expected {
+                return Err(error(&format!("expected {} fields, found {}", expected, fields.len())));
+            }
+
+            let elements = if parent.is_some() {
+                Some(OrbitalElements {
+                    semi_major_axis: Length::in_meters(number(5)?),
+                    eccentricity: number(6)?,
+                    inclination: Angle::in_degrees(number(7)?),
+                    argument_of_periapsis: Angle::in_degrees(number(8)?),
+                })
+            } else {
+                None
+            };
+
+            let body = CelestialBody {
+                name: fields[0].to_string(),
+                parent,
+                mass: Mass::in_kilograms(number(2)?),
+                radius: Length::in_meters(number(3)?),
+                rotation_period: Time::in_seconds(number(4)?),
+                elements,
+            };
+
+            if body.mass <= Mass::zero() || body.radius <= Length::zero() {
+                return Err(error("mass and radius must be positive"));
+            }
+
+            parsed.insert(body);
+        }
+
+        for body in parsed.bodies {
+            self.insert(body);
+        }
+
+        Ok(())
+    }
+
+    /// Reads a file in the format of [`Catalog::parse`].
+    pub fn load<P: AsRef<Path>>(&mut self, path: P) -> std::io::Result<()> {
+        let text = std::fs::read_to_string(path)?;
+        self.parse(&text).map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+}
+
+/// Describes the first invalid line of catalog data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCatalogError {
+    pub line: usize,
+    pub reason: String,
+}
+
+impl Display for ParseCatalogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl Error for ParseCatalogError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solar_system() {
+        let catalog = Catalog::solar_system();
+        let earth = catalog.get("earth").unwrap();
+
+        assert_eq!(18, catalog.len());
+        assert_eq!(Some("Sun"), earth.parent.as_deref());
+        assert_approx_eq!(9.82, earth.surface_gravity().value, 1e-3);
+        assert_approx_eq!(3.986e14, earth.gravitational_parameter().value, 1e-3);
+
+        let moons: Vec<&str> = catalog.children("Jupiter").map(|body| body.name.as_str()).collect();
+        assert_eq!(vec!["Io", "Europa", "Ganymede", "Callisto"], moons);
+
+        assert!(catalog.get("Venus").unwrap().rotation_period < Time::zero());
+        assert_eq!(None, catalog.get("Pluto"));
+    }
+
+    #[test]
+    fn orbits_around_parents() {
+        let catalog = Catalog::solar_system();
+
+        let year = catalog.orbit("Earth").unwrap().period().unwrap();
+        assert_approx_eq!(365.25, year.value / 86400.0, 1e-3);
+
+        let month = catalog.orbit("Moon").unwrap().period().unwrap();
+        assert_approx_eq!(27.45, month.value / 86400.0, 1e-2);
+
+        assert!(!catalog.orbit("Triton").unwrap().is_prograde());
+        assert_eq!(None, catalog.orbit("Sun"));
+    }
+
+    #[test]
+    fn user_data_extends_catalog() {
+        let mut catalog = Catalog::solar_system();
+        let data = "
+            # a dwarf planet and a moon
+            Ceres, Sun, 9.3835e20, 4.697e5, 32667, 4.1394e11, 0.0785, 0, 73.6
+            Tiny,  Ceres, 1e3, 1, 100, 1e6, 0, 0, 0  # made up
+            earth, Sun, 6e24, 6.4e6, 86400, 1.5e11, 0, 0, 0
+        ";
+
+        catalog.parse(data).unwrap();
+
+        assert_eq!(20, catalog.len());
+        assert_eq!(Mass::in_kilograms(1e3), catalog.get("tiny").unwrap().mass);
+        assert_eq!(Mass::in_kilograms(6e24), catalog.get("Earth").unwrap().mass);
+        assert!(catalog.orbit("Tiny").is_some());
+    }
+
+    #[test]
+    fn invalid_data_is_rejected() {
+        let mut catalog = Catalog::new();
+
+        let cases = [
+            ("Sun, -, 2e30, 7e8", 1, "expected 5 fields, found 4"),
+            ("Sun, -, 2e30, 7e8, 1\nEarth, Sun, 6e24, 6e6, 1, 1e11, 0, zero, 0", 2, "invalid number 'zero'"),
+            ("\nMoon, Earth, 7e22, 1.7e6, 1, 3.8e8, 0, 0, 0", 2, "unknown parent 'Earth'"),
+            ("Sun, -, -2e30, 7e8, 1", 1, "mass and radius must be positive"),
+        ];
+
+        for &(text, line, reason) in cases.iter() {
+            let error = catalog.parse(text).unwrap_err();
+            assert_eq!(ParseCatalogError { line, reason: reason.to_string() }, error);
+        }
+
+        assert!(catalog.is_empty());
+    }
+
+    #[test]
+    fn load_from_file() {
+        let path = std::env::temp_dir().join(format!("physics-catalog-{}.txt", std::process::id()));
+        std::fs::write(&path, "Vulcan, -, 1e24, 5e6, 90000\n").unwrap();
+
+        let mut catalog = Catalog::new();
+        catalog.load(&path).unwrap();
+        std::fs::write(&path, "Vulcan\n").unwrap();
+        let error = catalog.load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(1, catalog.len());
+        assert_eq!(std::io::ErrorKind::InvalidData, error.kind());
+    }
+}
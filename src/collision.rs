@@ -0,0 +1,276 @@
+use crate::*;
+
+/// The first contact between two moving shapes.
+///
+/// The normal is a unit vector at the contact point pointing from the obstacle toward the
+/// moving shape, which is the direction to push the moving shape to separate them.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Impact<F = Unframed> {
+    pub time: Time,
+    pub point: Vector<Meters, F>,
+    pub normal: Vector<Float, F>,
+}
+
+/// The first time within `duration` that a circle moving at `velocity` touches a stationary segment.
+///
+/// A circle that already overlaps the segment has an impact at time zero.
+pub fn sweep_circle_segment<F: Frame>(
+    circle: &Circle<Meters, F>,
+    velocity: Vector<MetersPerSecond, F>,
+    segment: &Segment<Meters, F>,
+    duration: Time,
+) -> Option<Impact<F>> {
+    let closest = segment.closest_point(circle.center);
+
+    if (circle.center - closest).magnitude() <= circle.radius {
+        let normal = separating_normal(circle.center, closest, segment);
+        return Some(Impact { time: Time::zero(), point: closest, normal });
+    }
+
+    let ray = Ray::from_unit_vector(circle.center, velocity.unit_vector()?);
+    let speed = velocity.magnitude();
+
+    // the faces of the segment, pushed out by the radius toward the circle
+    let face_hit = segment.direction().perpendicular().unit_vector().and_then(|normal| {
+        let normal = if normal.dot(circle.center - segment.start) < Length::zero() { -normal } else { normal };
+        let offset = normal * circle.radius;
+        let face = Segment::new(segment.start + offset, segment.end + offset);
+
+        ray.intersect_segment(&face).map(|distance| (distance, ray.point_at(distance) - offset, normal))
+    });
+
+    // the rounded ends of the segment
+    let end_hits = [segment.start, segment.end].iter().filter_map(|&end| {
+        ray.intersect_circle(&Circle::new(end, circle.radius)).map(|distance| {
+            let normal = (ray.point_at(distance) - end).unit_vector().unwrap_or(-ray.direction);
+            (distance, end, normal)
+        })
+    }).collect::<Vec<_>>();
+
+    face_hit.into_iter()
+        .chain(end_hits)
+        .min_by(|a, b| a.0.value.total_cmp(&b.0.value))
+        .map(|(distance, point, normal)| Impact { time: distance / speed, point, normal })
+        .filter(|impact| impact.time <= duration)
+}
+
+/// The first time within `duration` that two moving circles touch.
+///
+/// The normal points from `b` toward `a`. Circles that already overlap have an impact at time zero.
+pub fn sweep_circles<F: Frame>(
+    a: &Circle<Meters, F>,
+    velocity_a: Vector<MetersPerSecond, F>,
+    b: &Circle<Meters, F>,
+    velocity_b: Vector<MetersPerSecond, F>,
+    duration: Time,
+) -> Option<Impact<F>> {
+    let relative_velocity = velocity_a - velocity_b;
+    let combined = Circle::new(b.center, a.radius + b.radius);
+
+    let time = if combined.contains(a.center) {
+        Time::zero()
+    } else {
+        let ray = Ray::from_unit_vector(a.center, relative_velocity.unit_vector()?);
+        ray.intersect_circle(&combined)? / relative_velocity.magnitude()
+    };
+
+    if time > duration {
+        return None;
+    }
+
+    let center_a = a.center + velocity_a * time;
+    let center_b = b.center + velocity_b * time;
+    let normal = (center_a - center_b)
+        .unit_vector()
+        .or_else(|| (-relative_velocity).unit_vector())
+        .unwrap_or_else(|| Vector::from((0.0, 1.0)));
+
+    Some(Impact { time, point: center_b + normal * b.radius, normal })
+}
+
+/// The first time within `duration` that a point moving at `velocity` enters the box.
+///
+/// A point that starts inside has an impact at time zero, with the normal of the nearest face.
+pub fn sweep_ray_aabb<F: Frame>(
+    origin: Vector<Meters, F>,
+    velocity: Vector<MetersPerSecond, F>,
+    aabb: &Aabb<Meters, F>,
+    duration: Time,
+) -> Option<Impact<F>> {
+    if aabb.contains(origin) {
+        return Some(Impact { time: Time::zero(), point: origin, normal: nearest_face_normal(origin, aabb) });
+    }
+
+    let x = slab(origin.x, velocity.x, aabb.min.x, aabb.max.x)?;
+    let y = slab(origin.y, velocity.y, aabb.min.y, aabb.max.y)?;
+
+    let (enter, normal) = if x.0 > y.0 {
+        (x.0, Vector::from((-velocity.x.value.signum(), 0.0)))
+    } else {
+        (y.0, Vector::from((0.0, -velocity.y.value.signum())))
+    };
+
+    let exit = x.1.min(y.1);
+
+    if enter > exit || enter < Time::zero() || enter > duration {
+        return None;
+    }
+
+    Some(Impact { time: enter, point: origin + velocity * enter, normal })
+}
+
+/// The times at which a point enters and leaves the slab between `min` and `max` along one axis.
+fn slab(origin: Length, speed: Speed, min: Length, max: Length) -> Option<(Time, Time)> {
+    if speed == Speed::zero() {
+        return if min <= origin && origin <= max {
+            Some((Time::in_seconds(Float::NEG_INFINITY), Time::in_seconds(Float::INFINITY)))
+        } else {
+            None
+        };
+    }
+
+    let t1 = (min - origin) / speed;
+    let t2 = (max - origin) / speed;
+
+    Some((t1.min(t2), t1.max(t2)))
+}
+
+fn nearest_face_normal<F: Frame>(point: Vector<Meters, F>, aabb: &Aabb<Meters, F>) -> Vector<Float, F> {
+    let faces = [
+        (point.x - aabb.min.x, (-1.0, 0.0)),
+        (aabb.max.x - point.x, (1.0, 0.0)),
+        (point.y - aabb.min.y, (0.0, -1.0)),
+        (aabb.max.y - point.y, (0.0, 1.0)),
+    ];
+
+    let (_, normal) = faces.iter()
+        .min_by(|a, b| a.0.value.total_cmp(&b.0.value))
+        .unwrap();
+
+    Vector::from(*normal)
+}
+
+fn separating_normal<F: Frame>(center: Vector<Meters, F>, closest: Vector<Meters, F>, segment: &Segment<Meters, F>) -> Vector<Float, F> {
+    (center - closest)
+        .unit_vector()
+        .or_else(|| segment.direction().perpendicular().unit_vector())
+        .unwrap_or_else(|| Vector::from((0.0, 1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{p, v};
+
+    fn assert_impact(expected_time: Float, expected_point: Position, expected_normal: UnitVector, impact: Option<Impact>) {
+        let impact = impact.expect("expected an impact");
+        assert_approx_eq!(expected_time, impact.time.value);
+        assert_approx_eq!(expected_point.x.value, impact.point.x.value);
+        assert_approx_eq!(expected_point.y.value, impact.point.y.value);
+        assert_approx_eq!(expected_normal.x.value, impact.normal.x.value);
+        assert_approx_eq!(expected_normal.y.value, impact.normal.y.value);
+    }
+
+    #[test]
+    fn fast_circle_does_not_tunnel_through_thin_wall() {
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(0.5));
+        let wall = Segment::new(p(10.0, -5.0), p(10.0, 5.0));
+
+        let impact = sweep_circle_segment(&circle, v(1000.0, 0.0), &wall, Time::in_seconds(1.0));
+
+        assert_impact(0.0095, p(10.0, 0.0), UnitVector::from((-1.0, 0.0)), impact);
+    }
+
+    #[test]
+    fn circle_hits_segment_end() {
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(1.0));
+        let segment = Segment::new(p(5.0, 0.0), p(10.0, 0.0));
+
+        let impact = sweep_circle_segment(&circle, v(2.0, 0.0), &segment, Time::in_seconds(10.0));
+
+        assert_impact(2.0, p(5.0, 0.0), UnitVector::from((-1.0, 0.0)), impact);
+    }
+
+    #[test]
+    fn circle_misses_segment_within_duration() {
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(0.5));
+        let wall = Segment::new(p(10.0, -5.0), p(10.0, 5.0));
+
+        assert_eq!(None, sweep_circle_segment(&circle, v(1.0, 0.0), &wall, Time::in_seconds(1.0)));
+        assert_eq!(None, sweep_circle_segment(&circle, v(-1.0, 0.0), &wall, Time::in_seconds(100.0)));
+    }
+
+    #[test]
+    fn overlapping_circle_and_segment_collide_immediately() {
+        let circle = Circle::new(p(0.0, 0.5), Length::in_meters(1.0));
+        let segment = Segment::new(p(-5.0, 0.0), p(5.0, 0.0));
+
+        let impact = sweep_circle_segment(&circle, Velocity::zero(), &segment, Time::in_seconds(1.0));
+
+        assert_impact(0.0, p(0.0, 0.0), UnitVector::from((0.0, 1.0)), impact);
+    }
+
+    #[test]
+    fn moving_circles() {
+        let a = Circle::new(p(0.0, 0.0), Length::in_meters(1.0));
+        let b = Circle::new(p(10.0, 0.0), Length::in_meters(1.0));
+
+        let impact = sweep_circles(&a, v(2.0, 0.0), &b, v(-2.0, 0.0), Time::in_seconds(5.0));
+
+        assert_impact(2.0, p(5.0, 0.0), UnitVector::from((-1.0, 0.0)), impact);
+    }
+
+    #[test]
+    fn parallel_circles_do_not_collide() {
+        let a = Circle::new(p(0.0, 0.0), Length::in_meters(1.0));
+        let b = Circle::new(p(0.0, 5.0), Length::in_meters(1.0));
+
+        assert_eq!(None, sweep_circles(&a, v(2.0, 0.0), &b, v(2.0, 0.0), Time::in_seconds(5.0)));
+    }
+
+    #[test]
+    fn ray_enters_aabb() {
+        let aabb = Aabb::new(p(2.0, -1.0), p(4.0, 1.0));
+
+        let impact = sweep_ray_aabb(p(0.0, 0.0), v(1.0, 0.5), &aabb, Time::in_seconds(10.0));
+
+        assert_impact(2.0, p(2.0, 1.0), UnitVector::from((-1.0, 0.0)), impact);
+    }
+
+    #[test]
+    fn ray_enters_aabb_from_above() {
+        let aabb = Aabb::new(p(-1.0, -1.0), p(1.0, 1.0));
+
+        let impact = sweep_ray_aabb(p(0.5, 5.0), v(0.0, -2.0), &aabb, Time::in_seconds(10.0));
+
+        assert_impact(2.0, p(0.5, 1.0), UnitVector::from((0.0, 1.0)), impact);
+    }
+
+    #[test]
+    fn ray_misses_aabb() {
+        let aabb = Aabb::new(p(2.0, -1.0), p(4.0, 1.0));
+
+        assert_eq!(None, sweep_ray_aabb(p(0.0, 0.0), v(1.0, 2.0), &aabb, Time::in_seconds(10.0)));
+        assert_eq!(None, sweep_ray_aabb(p(0.0, 0.0), v(-1.0, 0.0), &aabb, Time::in_seconds(10.0)));
+        assert_eq!(None, sweep_ray_aabb(p(0.0, 0.0), v(1.0, 0.0), &aabb, Time::in_seconds(1.0)));
+    }
+
+    #[test]
+    fn ray_starting_inside_aabb_uses_nearest_face() {
+        let aabb = Aabb::new(p(0.0, 0.0), p(4.0, 4.0));
+
+        let impact = sweep_ray_aabb(p(3.5, 2.0), v(-1.0, 0.0), &aabb, Time::in_seconds(1.0));
+
+        assert_impact(0.0, p(3.5, 2.0), UnitVector::from((1.0, 0.0)), impact);
+    }
+
+    #[test]
+    fn not_a_number_does_not_panic() {
+        let aabb = Aabb::new(p(0.0, 0.0), p(4.0, 4.0));
+        assert_eq!(UnitVector::from((0.0, -1.0)), nearest_face_normal(p(Float::NAN, 1.0), &aabb));
+
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(0.5));
+        let wall = Segment::new(p(10.0, -5.0), p(10.0, 5.0));
+        let _ = sweep_circle_segment(&circle, v(Float::NAN, 1.0), &wall, Time::in_seconds(1.0));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::*;
+
+/// Newton's gravitational constant, CODATA 2018.
+pub const GRAVITATIONAL_CONSTANT: Scalar<MetersCubedPerKilogramSecondSquared> = Scalar::new(6.674_30e-11);
+
+/// The speed of light in a vacuum, exact by definition of the metre.
+pub const SPEED_OF_LIGHT: Speed = Scalar::new(299_792_458.0);
+
+/// The Boltzmann constant, exact by definition of the kelvin.
+pub const BOLTZMANN_CONSTANT: Scalar<JoulesPerKelvin> = Scalar::new(1.380_649e-23);
+
+/// The Stefan-Boltzmann constant, relating the temperature of a black body to the power it radiates.
+pub const STEFAN_BOLTZMANN_CONSTANT: Scalar<JoulesPerSecondMeterSquaredKelvinToTheFourth> = Scalar::new(5.670_374_419e-8);
+
+/// The Avogadro constant, exact by definition of the mole.
+pub const AVOGADRO_CONSTANT: Scalar<PerMole> = Scalar::new(6.022_140_76e23);
+
+/// The Planck constant, exact by definition of the kilogram.
+pub const PLANCK_CONSTANT: Scalar<JouleSeconds> = Scalar::new(6.626_070_15e-34);
+
+/// Standard gravity at sea level, used to convert between specific impulse and exhaust velocity.
+pub const STANDARD_GRAVITY: AccelScalar = Scalar::new(9.806_65);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newtonian_gravity() {
+        let sun = Mass::in_kilograms(1.988_47e30);
+        let earth = Mass::in_kilograms(5.972_2e24);
+        let distance = Length::in_meters(1.496e11);
+
+        let force: Force = GRAVITATIONAL_CONSTANT * sun * earth / (distance * distance);
+
+        assert_approx_eq!(3.542e22, force.value, 1e-3);
+    }
+
+    #[test]
+    fn mass_energy_and_photons() {
+        let energy: Energy = (SPEED_OF_LIGHT * SPEED_OF_LIGHT) * Mass::in_kilograms(1.0);
+        assert_approx_eq!(8.987_551_787e16, energy.value);
+
+        // green light at 540 THz
+        let photon: Energy = PLANCK_CONSTANT * Scalar::<PerSecond>::from(5.4e14);
+        assert_approx_eq!(3.578e-19, photon.value, 1e-3);
+    }
+
+    #[test]
+    fn thermal_constants() {
+        let thermal: Energy = BOLTZMANN_CONSTANT * Temperature::in_kelvin(300.0);
+        assert_approx_eq!(4.141_947e-21, thermal.value);
+
+        // the luminosity of the Sun from its surface temperature
+        let temperature = Temperature::in_kelvin(5772.0);
+        let radius = Length::in_meters(6.957e8);
+        let area = radius * radius * (4.0 * std::f64::consts::PI);
+        let luminosity: EnergyRate = STEFAN_BOLTZMANN_CONSTANT * ((temperature * temperature) * (temperature * temperature)) * area;
+        assert_approx_eq!(3.828e26, luminosity.value, 1e-3);
+
+        let count: Float = (AVOGADRO_CONSTANT * Scalar::<Moles>::in_moles(2.0)).value;
+        assert_approx_eq!(1.204_428_152e24, count);
+    }
+
+    #[test]
+    fn standard_gravity() {
+        let weight: Force = Mass::in_kilograms(10.0) * STANDARD_GRAVITY;
+        assert_approx_eq!(98.0665, weight.value);
+    }
+}
@@ -0,0 +1,333 @@
+use crate::*;
+
+/// A body taking part in a contact.
+///
+/// A body with infinite mass cannot be pushed and one with an infinite moment of inertia cannot
+/// be turned, which is what [`RigidBody::new`] assumes until rotation is supplied.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RigidBody<F = Unframed> {
+    pub mass: Mass,
+    pub moment_of_inertia: MomentOfInertia,
+    pub center_of_mass: Vector<Meters, F>,
+    pub velocity: Vector<MetersPerSecond, F>,
+    pub angular_velocity: AngluarSpeed,
+}
+
+impl<F: Frame> RigidBody<F> {
+    #[inline]
+    pub fn new(mass: Mass, center_of_mass: Vector<Meters, F>, velocity: Vector<MetersPerSecond, F>) -> Self {
+        RigidBody {
+            mass,
+            moment_of_inertia: MomentOfInertia::in_kilogram_meters_squared(Float::INFINITY),
+            center_of_mass,
+            velocity,
+            angular_velocity: AngluarSpeed::zero(),
+        }
+    }
+
+    /// An immovable body, such as the ground or a wall.
+    #[inline]
+    pub fn fixed(center_of_mass: Vector<Meters, F>) -> Self {
+        Self::new(Mass::in_kilograms(Float::INFINITY), center_of_mass, Vector::zero())
+    }
+
+    #[inline]
+    pub fn from_mass_properties(
+        properties: &MassProperties<F>,
+        velocity: Vector<MetersPerSecond, F>,
+        angular_velocity: AngluarSpeed,
+    ) -> Self {
+        RigidBody {
+            mass: properties.mass,
+            moment_of_inertia: properties.moment_of_inertia,
+            center_of_mass: properties.center_of_mass,
+            velocity,
+            angular_velocity,
+        }
+    }
+
+    #[inline]
+    pub fn with_rotation(self, moment_of_inertia: MomentOfInertia, angular_velocity: AngluarSpeed) -> Self {
+        RigidBody { moment_of_inertia, angular_velocity, ..self }
+    }
+
+    #[inline]
+    pub fn inverse_mass(&self) -> Scalar<PerKilogram> {
+        Scalar::<Float>::from(1.0) / self.mass
+    }
+
+    #[inline]
+    pub fn momentum(&self) -> Vector<KilogramMetersPerSecond, F> {
+        self.mass * self.velocity
+    }
+
+    /// The angular momentum about `point`, from both spin and motion of the center of mass.
+    #[inline]
+    pub fn angular_momentum_about(&self, point: Vector<Meters, F>) -> AngularMomentum {
+        let orbital = (self.center_of_mass - point).cross(self.momentum());
+
+        // a body that cannot turn has no spin, rather than infinite inertia times zero
+        if self.angular_velocity == AngluarSpeed::zero() {
+            return orbital;
+        }
+
+        self.moment_of_inertia * self.angular_velocity + orbital
+    }
+
+    /// The velocity of the material at `point`, including any spin.
+    #[inline]
+    pub fn velocity_at(&self, point: Vector<Meters, F>) -> Vector<MetersPerSecond, F> {
+        self.velocity + self.angular_velocity * (point - self.center_of_mass).perpendicular()
+    }
+
+    /// The change in velocity from applying `impulse` at `point`.
+    #[inline]
+    pub fn velocity_change(&self, impulse: Vector<KilogramMetersPerSecond, F>, point: Vector<Meters, F>) -> VelocityChange<F> {
+        VelocityChange {
+            linear: impulse * self.inverse_mass(),
+            angular: (point - self.center_of_mass).cross(impulse) / self.moment_of_inertia,
+        }
+    }
+
+    /// How readily the body gives way to an impulse along `direction` at `point`.
+    #[inline]
+    fn inverse_mass_along(&self, point: Vector<Meters, F>, direction: Vector<Float, F>) -> Scalar<PerKilogram> {
+        let arm = direction.cross(point - self.center_of_mass);
+        self.inverse_mass() + arm * arm / self.moment_of_inertia
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct VelocityChange<F = Unframed> {
+    pub linear: Vector<MetersPerSecond, F>,
+    pub angular: AngluarSpeed,
+}
+
+impl<F: Frame> VelocityChange<F> {
+    #[inline]
+    pub fn apply_to(&self, body: &mut RigidBody<F>) {
+        body.velocity += self.linear;
+        body.angular_velocity += self.angular;
+    }
+}
+
+/// How the surfaces in a contact behave.
+///
+/// Restitution is the ratio of separating to approaching speed, from zero for a perfectly inelastic
+/// collision to one for a perfectly elastic one. Friction is the Coulomb coefficient, which limits the
+/// tangential impulse to that multiple of the normal impulse.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ContactMaterial {
+    pub restitution: Float,
+    pub friction: Float,
+}
+
+/// Where two bodies `a` and `b` touch.
+///
+/// The normal is a unit vector pointing from `b` toward `a`, and the penetration is how far the
+/// bodies overlap along it.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Contact<F = Unframed> {
+    pub point: Vector<Meters, F>,
+    pub normal: Vector<Float, F>,
+    pub penetration: Length,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ContactResponse<F = Unframed> {
+    pub a: VelocityChange<F>,
+    pub b: VelocityChange<F>,
+    /// The impulse applied to `a`; `b` receives the opposite.
+    pub impulse: Vector<KilogramMetersPerSecond, F>,
+}
+
+impl<F: Frame> Contact<F> {
+    /// The velocity changes that stop the bodies approaching at the contact point.
+    ///
+    /// Bodies that are already separating, or that are both fixed, are left alone.
+    pub fn resolve(&self, a: &RigidBody<F>, b: &RigidBody<F>, material: &ContactMaterial) -> ContactResponse<F> {
+        let relative = a.velocity_at(self.point) - b.velocity_at(self.point);
+        let approach = self.normal.dot(relative);
+
+        let normal_mass = a.inverse_mass_along(self.point, self.normal)
+            + b.inverse_mass_along(self.point, self.normal);
+
+        if approach >= Speed::zero() || normal_mass == Scalar::zero() {
+            return ContactResponse::default();
+        }
+
+        let normal_impulse = -approach * (1.0 + material.restitution) / normal_mass;
+
+        let tangent = self.normal.perpendicular();
+        let tangent_mass = a.inverse_mass_along(self.point, tangent)
+            + b.inverse_mass_along(self.point, tangent);
+
+        // the impulse that would stop sliding, limited by the friction cone
+        let friction_impulse = -tangent.dot(relative) / tangent_mass;
+        let limit = normal_impulse * material.friction;
+        let friction_impulse = if friction_impulse.abs() > limit {
+            limit * friction_impulse.value.signum()
+        } else {
+            friction_impulse
+        };
+
+        let impulse = self.normal * normal_impulse + tangent * friction_impulse;
+
+        ContactResponse {
+            a: a.velocity_change(impulse, self.point),
+            b: b.velocity_change(-impulse, self.point),
+            impulse,
+        }
+    }
+
+    /// Displacements for `a` and `b` that push them apart along the normal.
+    ///
+    /// Removes `fraction` of the penetration beyond `slop`, moving lighter bodies further so that
+    /// resting contacts do not jitter.
+    pub fn positional_correction(
+        &self,
+        a: &RigidBody<F>,
+        b: &RigidBody<F>,
+        fraction: Float,
+        slop: Length,
+    ) -> (Vector<Meters, F>, Vector<Meters, F>) {
+        let depth = (self.penetration - slop).max(Length::zero()) * fraction;
+        let total = a.inverse_mass() + b.inverse_mass();
+
+        if depth == Length::zero() || total == Scalar::zero() {
+            return (Vector::zero(), Vector::zero());
+        }
+
+        let correction = self.normal * depth;
+
+        (correction * (a.inverse_mass() / total), -correction * (b.inverse_mass() / total))
+    }
+}
+
+impl<F: Frame> From<Impact<F>> for Contact<F> {
+    #[inline]
+    fn from(impact: Impact<F>) -> Self {
+        Contact { point: impact.point, normal: impact.normal, penetration: Length::zero() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{p, v};
+
+    fn ball(mass: Float, x: Float, vx: Float) -> RigidBody {
+        RigidBody::new(Mass::in_kilograms(mass), p(x, 0.0), v(vx, 0.0))
+    }
+
+    fn head_on() -> Contact {
+        Contact { point: p(0.0, 0.0), normal: UnitVector::from((-1.0, 0.0)), penetration: Length::zero() }
+    }
+
+    fn material(restitution: Float, friction: Float) -> ContactMaterial {
+        ContactMaterial { restitution, friction }
+    }
+
+    #[test]
+    fn elastic_collision_of_equal_masses_swaps_velocities() {
+        let a = ball(1.0, -1.0, 3.0);
+        let b = ball(1.0, 1.0, -1.0);
+
+        let response = head_on().resolve(&a, &b, &material(1.0, 0.0));
+
+        assert_eq!(v(-4.0, 0.0), response.a.linear);
+        assert_eq!(v(4.0, 0.0), response.b.linear);
+        assert_eq!(Impulse::in_kilogram_meters_per_second(-4.0, 0.0), response.impulse);
+    }
+
+    #[test]
+    fn inelastic_collision_conserves_momentum() {
+        let mut a = ball(3.0, -1.0, 2.0);
+        let mut b = ball(1.0, 1.0, -2.0);
+        let before = a.momentum() + b.momentum();
+
+        let response = head_on().resolve(&a, &b, &material(0.0, 0.0));
+        response.a.apply_to(&mut a);
+        response.b.apply_to(&mut b);
+
+        assert_eq!(before, a.momentum() + b.momentum());
+        assert_eq!(v(1.0, 0.0), a.velocity);
+        assert_eq!(v(1.0, 0.0), b.velocity);
+    }
+
+    #[test]
+    fn bounce_off_fixed_ground() {
+        let ball = RigidBody::new(Mass::in_kilograms(2.0), p(0.0, 1.0), v(0.0, -4.0));
+        let ground = RigidBody::fixed(p(0.0, -10.0));
+        let contact = Contact { point: p(0.0, 0.0), normal: UnitVector::from((0.0, 1.0)), penetration: Length::zero() };
+
+        let response = contact.resolve(&ball, &ground, &material(0.5, 0.0));
+
+        assert_eq!(v(0.0, 6.0), response.a.linear);
+        assert_eq!(Velocity::zero(), response.b.linear);
+    }
+
+    #[test]
+    fn friction_is_limited_by_the_normal_impulse() {
+        let ground = RigidBody::fixed(p(0.0, -10.0));
+        let contact = Contact { point: p(0.0, 0.0), normal: UnitVector::from((0.0, 1.0)), penetration: Length::zero() };
+
+        let sliding = RigidBody::new(Mass::in_kilograms(1.0), p(0.0, 1.0), v(5.0, -2.0));
+        let response = contact.resolve(&sliding, &ground, &material(0.0, 0.5));
+        assert_eq!(v(-1.0, 2.0), response.a.linear);
+
+        let creeping = RigidBody::new(Mass::in_kilograms(1.0), p(0.0, 1.0), v(0.5, -2.0));
+        let response = contact.resolve(&creeping, &ground, &material(0.0, 0.5));
+        assert_eq!(v(-0.5, 2.0), response.a.linear);
+    }
+
+    #[test]
+    fn off_center_hit_spins_the_target() {
+        let bullet = RigidBody::new(Mass::in_kilograms(1.0), p(-1.0, 1.0), v(10.0, 0.0));
+        let mut plank = RigidBody::new(Mass::in_kilograms(4.0), p(0.0, 0.0), Velocity::zero())
+            .with_rotation(MomentOfInertia::in_kilogram_meters_squared(2.0), AngluarSpeed::zero());
+        let mut bullet_after = bullet;
+
+        let contact = Contact { point: p(0.0, 1.0), normal: UnitVector::from((-1.0, 0.0)), penetration: Length::zero() };
+        let origin = Position::zero();
+        let before = bullet.angular_momentum_about(origin) + plank.angular_momentum_about(origin);
+
+        let response = contact.resolve(&bullet, &plank, &material(0.0, 0.0));
+        response.a.apply_to(&mut bullet_after);
+        response.b.apply_to(&mut plank);
+
+        // a push in +x above the center turns the plank clockwise
+        assert!(plank.angular_velocity < AngluarSpeed::zero());
+        assert_eq!(AngluarSpeed::zero(), response.a.angular);
+
+        let after = bullet_after.angular_momentum_about(origin) + plank.angular_momentum_about(origin);
+        assert_approx_eq!(before.value, after.value);
+
+        // the contact point ends up moving together along the normal
+        let closing = bullet_after.velocity_at(contact.point) - plank.velocity_at(contact.point);
+        assert_approx_eq!(0.0, closing.x.value, 1e-12);
+    }
+
+    #[test]
+    fn separating_bodies_are_left_alone() {
+        let a = ball(1.0, -1.0, -3.0);
+        let b = ball(1.0, 1.0, 1.0);
+
+        assert_eq!(ContactResponse::default(), head_on().resolve(&a, &b, &material(1.0, 0.5)));
+    }
+
+    #[test]
+    fn positional_correction_moves_lighter_body_further() {
+        let a = ball(1.0, 1.0, 0.0);
+        let b = ball(3.0, -1.0, 0.0);
+        let contact = Contact { point: p(0.0, 0.0), normal: UnitVector::from((1.0, 0.0)), penetration: Length::in_meters(0.5) };
+
+        let (move_a, move_b) = contact.positional_correction(&a, &b, 1.0, Length::in_meters(0.1));
+
+        assert_approx_eq!(0.3, move_a.x.value);
+        assert_approx_eq!(-0.1, move_b.x.value);
+
+        let (move_a, move_b) = contact.positional_correction(&a, &b, 1.0, Length::in_meters(1.0));
+        assert_eq!((Position::zero(), Position::zero()), (move_a, move_b));
+    }
+}
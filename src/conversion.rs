@@ -2,6 +2,7 @@ use std::ops::*;
 use crate::scalars::Scalar;
 use crate::vectors::Vector;
 use crate::units::*;
+use crate::{Float, Frame};
 
 macro_rules! divide_convert_scalars {
     ($numerator:ty, $denominator:ty, $result:ty) => (
@@ -41,26 +42,26 @@ macro_rules! divide_convert_scalars {
 
 macro_rules! divide_convert_vectors {
     ($numerator:ty, $denominator:ty, $result:ty) => (
-        impl Div<Scalar<$denominator>> for Vector<$numerator> {
-            type Output = Vector<$result>;
+        impl<F: Frame> Div<Scalar<$denominator>> for Vector<$numerator, F> {
+            type Output = Vector<$result, F>;
             #[inline]
             fn div(self, rhs: Scalar<$denominator>) -> Self::Output {
                 Self::Output::from((self.x / rhs, self.y / rhs))
             }
         }
 
-        impl Mul<Scalar<$denominator>> for Vector<$result> {
-            type Output = Vector<$numerator>;
+        impl<F: Frame> Mul<Scalar<$denominator>> for Vector<$result, F> {
+            type Output = Vector<$numerator, F>;
             #[inline]
             fn mul(self, rhs: Scalar<$denominator>) -> Self::Output {
                 Self::Output::from((self.x * rhs, self.y * rhs))
             }
         }
 
-        impl Mul<Vector<$result>> for Scalar<$denominator> {
-            type Output = Vector<$numerator>;
+        impl<F: Frame> Mul<Vector<$result, F>> for Scalar<$denominator> {
+            type Output = Vector<$numerator, F>;
             #[inline]
-            fn mul(self, rhs: Vector<$result>) -> Self::Output {
+            fn mul(self, rhs: Vector<$result, F>) -> Self::Output {
                 Self::Output::from((self * rhs.x, self * rhs.y))
             }
         }
@@ -91,6 +92,28 @@ macro_rules! squares_scalar {
                 Self::Output::from(self.value / rhs.value)
             }
         }
+
+        impl Sqrt for Scalar<$squared> {
+            type Output = Scalar<$normal>;
+            #[inline]
+            fn sqrt(self) -> Self::Output {
+                Self::Output::from(self.value.sqrt())
+            }
+        }
+    );
+}
+
+macro_rules! reciprocal {
+    ($unit:ty, $reciprocal:ty) => (
+        impl Reciprocal for $unit {
+            type Output = $reciprocal;
+        }
+
+        impl Reciprocal for $reciprocal {
+            type Output = $unit;
+        }
+
+        divide_convert_scalars!(Float, $unit, $reciprocal);
     );
 }
 
@@ -107,19 +130,82 @@ squares_scalar!(Meters, MetersSquared);
 squares_scalar!(Seconds, SecondsSquared);
 divide_convert_scalars!(MetersCubed, Meters, MetersSquared);
 divide_convert_scalars!(Kilograms, MetersCubed, KilogramsPerMeterCubed);
+divide_convert_scalars!(Kilograms, MetersSquared, KilogramsPerMeterSquared);
+divide_convert_scalars!(KilogramsPerMeterSquared, Meters, KilogramsPerMeterCubed);
+divide_convert_scalars!(KilogramMetersSquared, Kilograms, MetersSquared);
+divide_convert_scalars!(KilogramMetersSquared, MetersToTheFourth, KilogramsPerMeterSquared);
+squares_scalar!(MetersSquared, MetersToTheFourth);
 divide_convert!(Meters, Pixels, MetersPerPixel);
 divide_convert!(Radians, Seconds, RadiansPerSecond);
+reciprocal!(Meters, PerMeter);
+reciprocal!(Seconds, PerSecond);
+reciprocal!(Kilograms, PerKilogram);
+divide_convert_scalars!(MetersPerSecond, Meters, PerSecond);
+divide_convert!(KilogramMetersPerSecond, Kilograms, MetersPerSecond);
+divide_convert!(KilogramMetersPerSecond, Seconds, Newtons);
+divide_convert!(MetersPerSecond, PerKilogram, KilogramMetersPerSecond);
+divide_convert_scalars!(MetersSquared, KilogramMetersSquared, PerKilogram);
+divide_convert_scalars!(KilogramMetersSquaredPerSecond, Meters, KilogramMetersPerSecond);
+divide_convert_scalars!(KilogramMetersSquaredPerSecond, KilogramMetersSquared, RadiansPerSecond);
+squares_scalar!(MetersPerSecond, JoulesPerKilogram);
+divide_convert_scalars!(MetersCubedPerSecondSquared, Kilograms, MetersCubedPerKilogramSecondSquared);
+divide_convert_scalars!(MetersCubedPerSecondSquared, MetersSquared, MetersPerSecondSquared);
+divide_convert_scalars!(MetersCubedPerSecondSquared, Meters, JoulesPerKilogram);
+divide_convert_scalars!(JoulesPerKilogram, Meters, MetersPerSecondSquared);
+divide_convert_scalars!(MetersSquaredPerSecond, Meters, MetersPerSecond);
+divide_convert_scalars!(MetersCubedPerSecondSquared, MetersSquaredPerSecond, MetersPerSecond);
+divide_convert_scalars!(MetersSquaredPerSecond, Seconds, JoulesPerKilogram);
+divide_convert_scalars!(Newtons, KilogramsPerSecond, MetersPerSecond);
+divide_convert_scalars!(MetersSquaredPerSecondCubed, MetersPerSecond, MetersPerSecondSquared);
+divide_convert_scalars!(NewtonMetersSquared, MetersSquared, Newtons);
+divide_convert_scalars!(NewtonMetersSquared, Kilograms, MetersCubedPerSecondSquared);
+squares_scalar!(Kelvin, KelvinSquared);
+squares_scalar!(KelvinSquared, KelvinToTheFourth);
+divide_convert_scalars!(Joules, Kelvin, JoulesPerKelvin);
+divide_convert_scalars!(JoulesPerSecond, MetersSquared, JoulesPerSecondMeterSquared);
+divide_convert_scalars!(JoulesPerSecondMeterSquared, KelvinToTheFourth, JoulesPerSecondMeterSquaredKelvinToTheFourth);
+divide_convert_scalars!(JouleSeconds, Seconds, Joules);
+divide_convert_scalars!(Joules, PerSecond, JouleSeconds);
+reciprocal!(Moles, PerMole);
+divide_convert_scalars!(Newtons, MetersSquared, Pascals);
+divide_convert_scalars!(Pascals, KilogramsPerMeterCubed, JoulesPerKilogram);
+divide_convert_scalars!(JoulesPerKilogram, Kelvin, JoulesPerKilogramKelvin);
+
+// radians are dimensionless, so turning about a lever arm gives a tangential speed
+impl Mul<Scalar<Meters>> for Scalar<RadiansPerSecond> {
+    type Output = Scalar<MetersPerSecond>;
+    #[inline]
+    fn mul(self, rhs: Scalar<Meters>) -> Self::Output {
+        Self::Output::from(self.value * rhs.value)
+    }
+}
 
-impl Div<Scalar<MetersPerPixel>> for Vector<Meters> {
-    type Output = Vector<Pixels>;
+impl Mul<Scalar<RadiansPerSecond>> for Scalar<Meters> {
+    type Output = Scalar<MetersPerSecond>;
+    #[inline]
+    fn mul(self, rhs: Scalar<RadiansPerSecond>) -> Self::Output {
+        Self::Output::from(self.value * rhs.value)
+    }
+}
+
+impl<F: Frame> Mul<Vector<Meters, F>> for Scalar<RadiansPerSecond> {
+    type Output = Vector<MetersPerSecond, F>;
+    #[inline]
+    fn mul(self, rhs: Vector<Meters, F>) -> Self::Output {
+        Self::Output::from((self * rhs.x, self * rhs.y))
+    }
+}
+
+impl<F: Frame> Div<Scalar<MetersPerPixel>> for Vector<Meters, F> {
+    type Output = Vector<Pixels, F>;
     #[inline]
     fn div(self, rhs: Scalar<MetersPerPixel>) -> Self::Output {
         Self::Output::from((self.x.value / rhs.value, self.y.value / rhs.value))
     }
 }
 
-impl Mul<Scalar<MetersPerPixel>> for Vector<Pixels> {
-    type Output = Vector<Meters>;
+impl<F: Frame> Mul<Scalar<MetersPerPixel>> for Vector<Pixels, F> {
+    type Output = Vector<Meters, F>;
     #[inline]
     fn mul(self, rhs: Scalar<MetersPerPixel>) -> Self::Output {
         Self::Output::from((self.x.value * rhs.value, self.y.value * rhs.value))
@@ -129,7 +215,8 @@ impl Mul<Scalar<MetersPerPixel>> for Vector<Pixels> {
 
 #[cfg(test)]
 mod tests {
-    use crate::types::*;
+    use crate::*;
+    use crate::scalars::Scalar;
 
     #[test]
     fn time_and_velocity_to_position() {
@@ -159,7 +246,7 @@ mod tests {
 
         let expected = Velocity::in_meters_per_second(10.0, 15.0);
 
-        assert_eq!(expected, a.clone() * t);
+        assert_eq!(expected, a * t);
         assert_eq!(expected, t * a);
     }
 
@@ -193,6 +280,11 @@ mod tests {
         assert_eq!(expected, area / length);
     }
 
+    #[test]
+    fn area_sqrt_to_length() {
+        assert_eq!(Length::from(3.0), Area::from(9.0).sqrt());
+    }
+
     #[test]
     fn area_and_length_to_volume() {
         let area = Area::from(2.0);
@@ -213,6 +305,69 @@ mod tests {
         assert_eq!(expected, volume / length);
     }
 
+    #[test]
+    fn density_and_thickness_to_areal_density() {
+        let density = Density::in_kilograms_per_meter_cubed(1000.0);
+        let thickness = Length::in_meters(0.01);
+
+        assert_eq!(ArealDensity::in_kilograms_per_meter_squared(10.0), density * thickness);
+    }
+
+    #[test]
+    fn mass_and_area_to_moment_of_inertia() {
+        let mass = Mass::in_kilograms(2.0);
+        let area = Area::in_meters_squared(3.0);
+
+        assert_eq!(MomentOfInertia::in_kilogram_meters_squared(6.0), mass * area);
+    }
+
+    #[test]
+    fn force_and_time_to_impulse() {
+        let force = Force::in_newtons(4.0);
+        let time = Time::in_seconds(0.5);
+        let mass = Mass::in_kilograms(2.0);
+
+        let impulse = force * time;
+
+        assert_eq!(ImpulseScalar::in_kilogram_meters_per_second(2.0), impulse);
+        assert_eq!(Speed::in_meters_per_second(1.0), impulse / mass);
+        assert_eq!(Momentum::in_kilogram_meters_per_second(6.0, 2.0), mass * Velocity::in_meters_per_second(3.0, 1.0));
+    }
+
+    #[test]
+    fn mass_flow_and_exhaust_velocity_to_thrust() {
+        let flow = MassRate::in_kilograms_per_second(250.0);
+        let exhaust = Speed::in_meters_per_second(3000.0);
+
+        assert_eq!(Force::in_newtons(750_000.0), flow * exhaust);
+        assert_eq!(exhaust, Force::in_newtons(750_000.0) / flow);
+    }
+
+    #[test]
+    fn specific_angular_momentum_and_gravitational_parameter() {
+        let position = Position::in_meters(4.0, 0.0);
+        let velocity = Velocity::in_meters_per_second(1.0, 3.0);
+        let momentum: SpecificAngularMomentum = position.cross(velocity);
+        let mu = GravitationalParameter::from(36.0);
+
+        assert_eq!(SpecificAngularMomentum::from(12.0), momentum);
+        assert_eq!(Speed::in_meters_per_second(3.0), mu / momentum);
+        assert_eq!(Length::in_meters(4.0), momentum / (mu / momentum));
+        assert_eq!(mu, momentum * Speed::in_meters_per_second(3.0));
+        assert_eq!(Time::in_seconds(2.0), momentum / EnergyDensity::in_joules_per_kilogram(6.0));
+    }
+
+    #[test]
+    fn acceleration_along_velocity() {
+        let velocity = Velocity::in_meters_per_second(3.0, -2.0);
+        let acceleration = Acceleration::in_meters_per_second_squared(4.0, 1.0);
+        let rate: Scalar<MetersSquaredPerSecondCubed> = acceleration.dot(velocity);
+
+        assert_eq!(Scalar::from(10.0), rate);
+        assert_eq!(rate, velocity.dot(acceleration));
+        assert_eq!(Speed::in_meters_per_second(2.5), rate / AccelScalar::from(4.0));
+    }
+
     #[test]
     fn pixels() {
         let position = Position::in_meters(2.0, 3.0);
@@ -221,4 +376,13 @@ mod tests {
 
         assert_eq!(Resolution::from((4.0, 6.0)), resolution);
     }
+
+    #[test]
+    fn length_and_reciprocal_to_dimensionless() {
+        let length = Length::in_meters(4.0);
+        let reciprocal = Scalar::<Float>::from(1.0) / length;
+
+        assert_eq!(Scalar::<PerMeter>::from(0.25), reciprocal);
+        assert_eq!(Scalar::<Float>::from(1.0), length * reciprocal);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::fmt::Debug;
+
+/// A marker for the coordinate space that a `Vector` or `Point` is expressed in.
+///
+/// Arithmetic is only defined between values in the same frame, so positions in different
+/// spaces cannot be mixed by accident. Use a `Transform2` to move between frames:
+///
+/// ```compile_fail
+/// use physics::*;
+///
+/// let world = Vector::<Meters, World>::in_meters(1.0, 2.0);
+/// let local = Vector::<Meters, Local>::in_meters(3.0, 4.0);
+///
+/// let _ = world + local;
+/// ```
+///
+/// Nor can a value be relabelled into another frame without a transform:
+///
+/// ```compile_fail
+/// use physics::*;
+///
+/// let local = Vector::<Meters, Local>::in_meters(3.0, 4.0);
+/// let _: Vector<Meters, World> = local.into_frame();
+/// ```
+pub trait Frame : 'static + Send + Sync + Copy + PartialEq + Default + Debug {}
+
+macro_rules! define_frame {
+    ($frame:ident) => (
+        #[derive(Debug, Default, PartialEq, Copy, Clone)]
+        pub struct $frame;
+
+        impl Frame for $frame {}
+    );
+}
+
+define_frame!(Unframed);
+define_frame!(World);
+define_frame!(Local);
+define_frame!(Screen);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn operators_work_within_a_frame() {
+        let p = Vector::<Meters, World>::in_meters(1.0, 2.0);
+        let v = Vector::<MetersPerSecond, World>::in_meters_per_second(3.0, 4.0);
+        let t = Time::in_seconds(2.0);
+
+        let result: Vector<Meters, World> = p + v * t;
+
+        assert_eq!(Vector::in_meters(7.0, 10.0), result);
+    }
+
+    #[test]
+    fn unit_vector_keeps_frame() {
+        let v = Vector::<Meters, Screen>::in_meters(0.0, 2.0);
+        let direction: Vector<Float, Screen> = v.unit_vector().unwrap();
+
+        assert_eq!(Vector::in_meters(0.0, 2.0), direction * Length::in_meters(2.0));
+    }
+
+    #[test]
+    fn into_frame_keeps_components() {
+        let local = Vector::<Meters, Local>::in_meters(1.0, 2.0);
+        let world: Vector<Meters, World> = local.into_frame();
+
+        assert_eq!(local.x, world.x);
+        assert_eq!(local.y, world.y);
+    }
+}
@@ -0,0 +1,550 @@
+use std::ops::*;
+use std::f64::consts::PI;
+use crate::*;
+
+/// The straight line between two points.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Segment<T, F = Unframed> {
+    pub start: Vector<T, F>,
+    pub end: Vector<T, F>,
+}
+
+impl<T: Unit, F: Frame> Segment<T, F> {
+    #[inline]
+    pub fn new(start: Vector<T, F>, end: Vector<T, F>) -> Self {
+        Segment { start, end }
+    }
+
+    /// The vector from `start` to `end`.
+    #[inline]
+    pub fn direction(&self) -> Vector<T, F> {
+        self.end - self.start
+    }
+
+    #[inline]
+    pub fn length(&self) -> Scalar<T> {
+        self.direction().magnitude()
+    }
+
+    /// Linear interpolation, returning `start` at `t = 0` and `end` at `t = 1`.
+    #[inline]
+    pub fn point_at(&self, t: Float) -> Vector<T, F> {
+        self.start + self.direction() * t
+    }
+
+    #[inline]
+    pub fn midpoint(&self) -> Vector<T, F> {
+        self.point_at(0.5)
+    }
+
+    #[inline]
+    pub fn aabb(&self) -> Aabb<T, F> {
+        Aabb::new(self.start, self.end)
+    }
+}
+
+impl<T: Unit, A: Unit, F: Frame> Segment<T, F>
+    where Scalar<T>: Mul<Output=Scalar<A>>
+{
+    /// The fraction along the segment of the point closest to `point`, from 0 to 1.
+    #[inline]
+    pub fn closest_fraction(&self, point: Vector<T, F>) -> Float {
+        let direction = self.direction();
+        let length_squared = direction.dot(direction);
+
+        if length_squared == Scalar::zero() {
+            return 0.0;
+        }
+
+        ((point - self.start).dot(direction) / length_squared).clamp(0.0, 1.0)
+    }
+
+    #[inline]
+    pub fn closest_point(&self, point: Vector<T, F>) -> Vector<T, F> {
+        self.point_at(self.closest_fraction(point))
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector<T, F>) -> Scalar<T> {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// The first point along `self` that also lies on `other`, or `None` if they do not touch.
+    pub fn intersection(&self, other: &Self) -> Option<Vector<T, F>> {
+        let r = self.direction();
+        let s = other.direction();
+        let offset = other.start - self.start;
+
+        let denominator = r.cross(s);
+        let offset_cross_r = offset.cross(r);
+
+        if denominator != Scalar::zero() {
+            let t = offset.cross(s) / denominator;
+            let u = offset_cross_r / denominator;
+
+            return if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+                Some(self.point_at(t))
+            } else {
+                None
+            };
+        }
+
+        if offset_cross_r != Scalar::zero() {
+            return None;
+        }
+
+        // parallel and collinear, or one of the segments is a single point
+        let length_squared = r.dot(r);
+
+        if length_squared == Scalar::zero() {
+            return if other.distance_to_point(self.start) == Scalar::zero() {
+                Some(self.start)
+            } else {
+                None
+            };
+        }
+
+        if s.dot(s) == Scalar::zero() {
+            return if self.distance_to_point(other.start) == Scalar::zero() {
+                Some(other.start)
+            } else {
+                None
+            };
+        }
+
+        let t0 = offset.dot(r) / length_squared;
+        let t1 = t0 + s.dot(r) / length_squared;
+        let low = t0.min(t1).max(0.0);
+        let high = t0.max(t1).min(1.0);
+
+        if low <= high {
+            Some(self.point_at(low))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+/// A half-line starting at `origin` and extending forever along the unit vector `direction`.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Ray<T, F = Unframed> {
+    pub origin: Vector<T, F>,
+    pub direction: Vector<Float, F>,
+}
+
+impl<T: Unit, F: Frame> Ray<T, F> {
+    /// Returns `None` if `direction` is zero.
+    #[inline]
+    pub fn new(origin: Vector<T, F>, direction: Vector<T, F>) -> Option<Self> {
+        direction.unit_vector().map(|direction| Ray { origin, direction })
+    }
+
+    #[inline]
+    pub fn from_unit_vector(origin: Vector<T, F>, direction: Vector<Float, F>) -> Self {
+        Ray { origin, direction }
+    }
+
+    #[inline]
+    pub fn point_at(&self, distance: Scalar<T>) -> Vector<T, F> {
+        self.origin + self.direction * distance
+    }
+
+    #[inline]
+    pub fn closest_point(&self, point: Vector<T, F>) -> Vector<T, F> {
+        let distance = self.direction.dot(point - self.origin).max(Scalar::zero());
+        self.point_at(distance)
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector<T, F>) -> Scalar<T> {
+        (point - self.closest_point(point)).magnitude()
+    }
+}
+
+impl<T: Unit, A: Unit, F: Frame> Ray<T, F>
+    where
+        Scalar<T>: Mul<Output=Scalar<A>>,
+        Scalar<A>: Div<Scalar<T>, Output=Scalar<T>> + Sqrt<Output=Scalar<T>>,
+{
+    /// The distance along the ray to the first point on the segment, if it is hit.
+    pub fn intersect_segment(&self, segment: &Segment<T, F>) -> Option<Scalar<T>> {
+        let s = segment.direction();
+        let to_start = segment.start - self.origin;
+        let denominator = self.direction.cross(s);
+
+        if denominator == Scalar::zero() {
+            if self.direction.cross(to_start) != Scalar::zero() {
+                return None;
+            }
+
+            let near = self.direction.dot(to_start);
+            let far = self.direction.dot(segment.end - self.origin);
+
+            return if near.max(far) < Scalar::zero() {
+                None
+            } else {
+                Some(near.min(far).max(Scalar::zero()))
+            };
+        }
+
+        let distance = to_start.cross(s) / denominator;
+        let fraction = -self.direction.cross(to_start) / denominator;
+
+        if distance >= Scalar::zero() && (0.0..=1.0).contains(&fraction) {
+            Some(distance)
+        } else {
+            None
+        }
+    }
+
+    /// The distance along the ray to where it first touches the circle, or zero if it starts inside.
+    pub fn intersect_circle(&self, circle: &Circle<T, F>) -> Option<Scalar<T>> {
+        let offset = self.origin - circle.center;
+        let b = self.direction.dot(offset);
+        let c = offset.dot(offset) - circle.radius * circle.radius;
+
+        if c <= Scalar::zero() {
+            return Some(Scalar::zero());
+        }
+
+        if b > Scalar::zero() {
+            return None;
+        }
+
+        let discriminant = b * b - c;
+
+        if discriminant < Scalar::zero() {
+            None
+        } else {
+            Some(-b - discriminant.sqrt())
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Circle<T, F = Unframed> {
+    pub center: Vector<T, F>,
+    pub radius: Scalar<T>,
+}
+
+impl<T: Unit, F: Frame> Circle<T, F> {
+    #[inline]
+    pub fn new(center: Vector<T, F>, radius: Scalar<T>) -> Self {
+        Circle { center, radius }
+    }
+
+    #[inline]
+    pub fn circumference(&self) -> Scalar<T> {
+        self.radius * (2.0 * PI)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector<T, F>) -> bool {
+        (point - self.center).magnitude() <= self.radius
+    }
+
+    /// The distance from the edge of the circle, negative inside.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector<T, F>) -> Scalar<T> {
+        (point - self.center).magnitude() - self.radius
+    }
+
+    /// The point on the edge closest to `point`, or `None` if `point` is the center.
+    #[inline]
+    pub fn closest_point(&self, point: Vector<T, F>) -> Option<Vector<T, F>> {
+        (point - self.center)
+            .unit_vector()
+            .map(|direction| self.center + direction * self.radius)
+    }
+
+    #[inline]
+    pub fn intersects_circle(&self, other: &Self) -> bool {
+        (other.center - self.center).magnitude() <= self.radius + other.radius
+    }
+
+    #[inline]
+    pub fn aabb(&self) -> Aabb<T, F> {
+        Aabb::from_center(self.center, Vector::from((self.radius, self.radius)))
+    }
+}
+
+impl<T: Unit, A: Unit, F: Frame> Circle<T, F>
+    where Scalar<T>: Mul<Output=Scalar<A>>
+{
+    #[inline]
+    pub fn area(&self) -> Scalar<A> {
+        self.radius * self.radius * PI
+    }
+
+    #[inline]
+    pub fn intersects_segment(&self, segment: &Segment<T, F>) -> bool {
+        segment.distance_to_point(self.center) <= self.radius
+    }
+}
+
+/// The order in which a polygon's vertices go around it, with the y-axis pointing up.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A simple polygon, with an implicit edge from the last vertex back to the first.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Polygon<T, F = Unframed> {
+    pub vertices: Vec<Vector<T, F>>,
+}
+
+impl<T: Unit, F: Frame> Polygon<T, F> {
+    #[inline]
+    pub fn new(vertices: Vec<Vector<T, F>>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item=Segment<T, F>> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&start, &end)| Segment::new(start, end))
+    }
+
+    #[inline]
+    pub fn perimeter(&self) -> Scalar<T> {
+        self.edges().fold(Scalar::zero(), |sum, edge| sum + edge.length())
+    }
+
+    #[inline]
+    pub fn aabb(&self) -> Option<Aabb<T, F>> {
+        Aabb::from_points(self.vertices.iter().copied())
+    }
+}
+
+impl<T: Unit, A: Unit, F: Frame> Polygon<T, F>
+    where Scalar<T>: Mul<Output=Scalar<A>>
+{
+    /// Positive for counter-clockwise vertices, negative for clockwise.
+    pub fn signed_area(&self) -> Scalar<A> {
+        self.edges().fold(Scalar::zero(), |sum, edge| sum + edge.start.cross(edge.end)) / 2.0
+    }
+
+    #[inline]
+    pub fn area(&self) -> Scalar<A> {
+        self.signed_area().abs()
+    }
+
+    /// Returns `None` if the polygon has no area.
+    #[inline]
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+
+        if area > Scalar::zero() {
+            Some(Winding::CounterClockwise)
+        } else if area < Scalar::zero() {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// The center of mass of the polygon's area, or `None` if it has no area.
+    pub fn centroid(&self) -> Option<Vector<T, F>> {
+        let area = self.signed_area();
+
+        if area == Scalar::zero() {
+            return None;
+        }
+
+        let centroid = self.edges().fold(Vector::zero(), |sum, edge| {
+            sum + (edge.start + edge.end) * (edge.start.cross(edge.end) / (area * 6.0))
+        });
+
+        Some(centroid)
+    }
+
+    /// The number of times the boundary winds counter-clockwise around `point`.
+    pub fn winding_number(&self, point: Vector<T, F>) -> i32 {
+        let mut winding_number = 0;
+
+        for edge in self.edges() {
+            let side = edge.direction().cross(point - edge.start);
+
+            if edge.start.y <= point.y {
+                if edge.end.y > point.y && side > Scalar::zero() {
+                    winding_number += 1;
+                }
+            } else if edge.end.y <= point.y && side < Scalar::zero() {
+                winding_number -= 1;
+            }
+        }
+
+        winding_number
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vector<T, F>) -> bool {
+        self.winding_number(point) != 0
+    }
+
+    /// The point on the boundary closest to `point`, or `None` if there are no vertices.
+    pub fn closest_point(&self, point: Vector<T, F>) -> Option<Vector<T, F>> {
+        self.edges()
+            .map(|edge| edge.closest_point(point))
+            .min_by(|a, b| {
+                let a = (point - *a).magnitude_squared();
+                let b = (point - *b).magnitude_squared();
+                a.total_cmp(&b)
+            })
+    }
+
+    /// The distance to the boundary, or `None` if there are no vertices.
+    #[inline]
+    pub fn distance_to_point(&self, point: Vector<T, F>) -> Option<Scalar<T>> {
+        self.closest_point(point).map(|closest| (point - closest).magnitude())
+    }
+
+    #[inline]
+    pub fn intersects_segment(&self, segment: &Segment<T, F>) -> bool {
+        self.contains(segment.start) || self.edges().any(|edge| edge.intersects(segment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::p;
+
+    fn square() -> Polygon<Meters> {
+        Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)])
+    }
+
+    #[test]
+    fn segment_closest_point_is_clamped() {
+        let segment = Segment::new(p(0.0, 0.0), p(4.0, 0.0));
+
+        assert_eq!(p(1.0, 0.0), segment.closest_point(p(1.0, 3.0)));
+        assert_eq!(p(4.0, 0.0), segment.closest_point(p(6.0, 1.0)));
+        assert_eq!(Length::in_meters(3.0), segment.distance_to_point(p(1.0, 3.0)));
+    }
+
+    #[test]
+    fn segment_intersection() {
+        let a = Segment::new(p(0.0, 0.0), p(4.0, 4.0));
+        let b = Segment::new(p(0.0, 4.0), p(4.0, 0.0));
+        let c = Segment::new(p(5.0, 0.0), p(5.0, 4.0));
+
+        assert_eq!(Some(p(2.0, 2.0)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+    }
+
+    #[test]
+    fn collinear_segments_intersect_where_they_overlap() {
+        let a = Segment::new(p(0.0, 0.0), p(4.0, 0.0));
+        let b = Segment::new(p(6.0, 0.0), p(2.0, 0.0));
+        let c = Segment::new(p(5.0, 0.0), p(6.0, 0.0));
+
+        assert_eq!(Some(p(2.0, 0.0)), a.intersection(&b));
+        assert_eq!(None, a.intersection(&c));
+    }
+
+    #[test]
+    fn ray_hits_segment() {
+        let ray = Ray::new(p(0.0, 0.0), p(1.0, 0.0)).unwrap();
+
+        assert_eq!(Some(Length::in_meters(3.0)), ray.intersect_segment(&Segment::new(p(3.0, -1.0), p(3.0, 1.0))));
+        assert_eq!(None, ray.intersect_segment(&Segment::new(p(-3.0, -1.0), p(-3.0, 1.0))));
+        assert_eq!(None, ray.intersect_segment(&Segment::new(p(3.0, 1.0), p(3.0, 2.0))));
+    }
+
+    #[test]
+    fn ray_hits_circle() {
+        let ray = Ray::new(p(0.0, 0.0), p(1.0, 0.0)).unwrap();
+
+        assert_eq!(Some(Length::in_meters(4.0)), ray.intersect_circle(&Circle::new(p(5.0, 0.0), Length::in_meters(1.0))));
+        assert_eq!(None, ray.intersect_circle(&Circle::new(p(-5.0, 0.0), Length::in_meters(1.0))));
+        assert_eq!(None, ray.intersect_circle(&Circle::new(p(5.0, 2.0), Length::in_meters(1.0))));
+    }
+
+    #[test]
+    fn ray_closest_point() {
+        let ray = Ray::new(p(0.0, 0.0), p(0.0, 2.0)).unwrap();
+
+        assert_eq!(p(0.0, 3.0), ray.closest_point(p(1.0, 3.0)));
+        assert_eq!(Length::in_meters(5.0), ray.distance_to_point(p(3.0, -4.0)));
+    }
+
+    #[test]
+    fn circle_measurements() {
+        let circle = Circle::new(p(1.0, 1.0), Length::in_meters(2.0));
+
+        assert_eq!(Area::in_meters_squared(4.0 * PI), circle.area());
+        assert_eq!(Length::in_meters(4.0 * PI), circle.circumference());
+        assert_eq!(Some(p(3.0, 1.0)), circle.closest_point(p(5.0, 1.0)));
+        assert_eq!(Length::in_meters(2.0), circle.distance_to_point(p(5.0, 1.0)));
+    }
+
+    #[test]
+    fn circle_intersections() {
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(1.0));
+
+        assert!(circle.intersects_circle(&Circle::new(p(1.5, 0.0), Length::in_meters(1.0))));
+        assert!(!circle.intersects_circle(&Circle::new(p(3.0, 0.0), Length::in_meters(1.0))));
+        assert!(circle.intersects_segment(&Segment::new(p(-2.0, 0.5), p(2.0, 0.5))));
+        assert!(!circle.intersects_segment(&Segment::new(p(-2.0, 1.5), p(2.0, 1.5))));
+    }
+
+    #[test]
+    fn polygon_area_and_perimeter() {
+        let square = square();
+
+        assert_eq!(Area::in_meters_squared(4.0), square.area());
+        assert_eq!(Length::in_meters(8.0), square.perimeter());
+    }
+
+    #[test]
+    fn polygon_winding() {
+        let mut square = square();
+        assert_eq!(Some(Winding::CounterClockwise), square.winding());
+
+        square.vertices.reverse();
+        assert_eq!(Some(Winding::Clockwise), square.winding());
+        assert_eq!(Area::in_meters_squared(-4.0), square.signed_area());
+    }
+
+    #[test]
+    fn polygon_centroid() {
+        let triangle = Polygon::new(vec![p(0.0, 0.0), p(3.0, 0.0), p(0.0, 3.0)]);
+
+        assert_eq!(Some(p(1.0, 1.0)), square().centroid());
+        assert_eq!(Some(p(1.0, 1.0)), triangle.centroid());
+        assert_eq!(None, Polygon::new(vec![p(0.0, 0.0), p(1.0, 1.0)]).centroid());
+    }
+
+    #[test]
+    fn polygon_contains() {
+        let square = square();
+
+        assert!(square.contains(p(1.0, 1.0)));
+        assert!(!square.contains(p(3.0, 1.0)));
+        assert_eq!(-1, Polygon::new(square.vertices.iter().rev().copied().collect()).winding_number(p(1.0, 1.0)));
+    }
+
+    #[test]
+    fn polygon_distance() {
+        let square = square();
+
+        assert_eq!(Some(p(2.0, 1.0)), square.closest_point(p(5.0, 1.0)));
+        assert_eq!(Some(Length::in_meters(3.0)), square.distance_to_point(p(5.0, 1.0)));
+        assert!(square.intersects_segment(&Segment::new(p(1.0, 1.0), p(1.5, 1.5))));
+        assert!(!square.intersects_segment(&Segment::new(p(3.0, 3.0), p(4.0, 4.0))));
+
+        // a point that is not a number has no meaningful distance, but must not panic
+        assert!(square.closest_point(p(Float::NAN, 1.0)).is_some());
+    }
+}
@@ -0,0 +1,237 @@
+use std::f64::consts::PI;
+use crate::*;
+
+/// A spherical shell of constant density, extending out from the previous layer.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Layer {
+    pub outer_radius: Length,
+    pub density: Density,
+}
+
+/// A model of the gravity around a body.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GravityField<F = Unframed> {
+    /// All of the mass at a single point.
+    Point { mass: Mass, position: Vector<Meters, F> },
+    /// The same acceleration everywhere, with zero potential at the origin.
+    Uniform { acceleration: Vector<MetersPerSecondSquared, F> },
+    /// A sphere built of concentric layers, listed from the innermost outward.
+    Layered { center: Vector<Meters, F>, layers: Vec<Layer> },
+}
+
+impl<F: Frame> GravityField<F> {
+    /// The acceleration felt by a body at `position`, zero at the center of a point mass.
+    pub fn acceleration(&self, position: Vector<Meters, F>) -> Vector<MetersPerSecondSquared, F> {
+        let (center, mass) = match self {
+            GravityField::Uniform { acceleration } => return *acceleration,
+            GravityField::Point { mass, position } => (*position, *mass),
+            GravityField::Layered { center, layers } => {
+                (*center, enclosed_mass(layers, (position - *center).magnitude()))
+            }
+        };
+
+        let offset = center - position;
+        let distance = offset.magnitude();
+
+        match offset.unit_vector() {
+            Some(direction) => direction * (GRAVITATIONAL_CONSTANT * mass / (distance * distance)),
+            None => Vector::zero(),
+        }
+    }
+
+    /// The potential energy per unit mass at `position`, zero infinitely far from a body.
+    pub fn potential(&self, position: Vector<Meters, F>) -> EnergyDensity {
+        match self {
+            GravityField::Uniform { acceleration } => -acceleration.dot(position),
+            GravityField::Point { mass, position: center } => {
+                -(GRAVITATIONAL_CONSTANT * *mass / (position - *center).magnitude())
+            }
+            GravityField::Layered { center, layers } => {
+                let radius = (position - *center).magnitude();
+
+                // each shell outside the radius adds a constant potential inside it
+                let mut lower = Length::zero();
+                let mut outer = 0.0;
+                for layer in layers {
+                    let start = lower.max(radius);
+                    if layer.outer_radius > start {
+                        outer += layer.density.value * (layer.outer_radius.value.powi(2) - start.value.powi(2));
+                    }
+                    lower = layer.outer_radius;
+                }
+
+                let outer = EnergyDensity::in_joules_per_kilogram(2.0 * PI * GRAVITATIONAL_CONSTANT.value * outer);
+
+                if radius == Length::zero() {
+                    -outer
+                } else {
+                    -(GRAVITATIONAL_CONSTANT * enclosed_mass(layers, radius) / radius) - outer
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn potential_energy(&self, mass: Mass, position: Vector<Meters, F>) -> Energy {
+        self.potential(position) * mass
+    }
+}
+
+/// The mass of the layers within `radius` of the center.
+fn enclosed_mass(layers: &[Layer], radius: Length) -> Mass {
+    let mut lower = Length::zero();
+    let mut mass = Mass::zero();
+
+    for layer in layers {
+        if lower >= radius {
+            break;
+        }
+
+        let upper = layer.outer_radius.min(radius);
+        mass += layer.density * (sphere_volume(upper) - sphere_volume(lower));
+        lower = layer.outer_radius;
+    }
+
+    mass
+}
+
+#[inline]
+fn sphere_volume(radius: Length) -> Volume {
+    radius * radius * radius * (4.0 / 3.0 * PI)
+}
+
+/// The speed needed at `radius` from a body of `mass` to never fall back.
+#[inline]
+pub fn escape_speed(mass: Mass, radius: Length) -> Speed {
+    (GRAVITATIONAL_CONSTANT * mass / radius * 2.0).sqrt()
+}
+
+/// The speed of a circular orbit at `radius` around a body of `mass`.
+#[inline]
+pub fn circular_speed(mass: Mass, radius: Length) -> Speed {
+    (GRAVITATIONAL_CONSTANT * mass / radius).sqrt()
+}
+
+/// The distance within which a body of `mass`, orbiting a `primary_mass` at `semi_major_axis`,
+/// dominates the motion of smaller objects, as used for patched conics.
+#[inline]
+pub fn sphere_of_influence(semi_major_axis: Length, mass: Mass, primary_mass: Mass) -> Length {
+    semi_major_axis * (mass / primary_mass).powf(0.4)
+}
+
+/// The distance within which a body of `mass`, orbiting a `primary_mass`, can hold on to moons.
+#[inline]
+pub fn hill_radius(semi_major_axis: Length, eccentricity: Float, mass: Mass, primary_mass: Mass) -> Length {
+    semi_major_axis * (1.0 - eccentricity) * (mass / (primary_mass * 3.0)).cbrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARTH: Float = 5.972_2e24;
+    const SUN: Float = 1.988_47e30;
+    const RADIUS: Float = 6.371e6;
+
+    fn uniform_planet() -> (GravityField, GravityField) {
+        let mass = Mass::in_kilograms(EARTH);
+        let radius = Length::in_meters(RADIUS);
+        let density = mass / sphere_volume(radius);
+
+        (
+            GravityField::Point { mass, position: Position::zero() },
+            GravityField::Layered { center: Position::zero(), layers: vec![Layer { outer_radius: radius, density }] },
+        )
+    }
+
+    #[test]
+    fn point_mass() {
+        let (point, _) = uniform_planet();
+        let surface = Position::in_meters(0.0, RADIUS);
+
+        let acceleration = point.acceleration(surface);
+        assert_eq!(0.0, acceleration.x.value);
+        assert_approx_eq!(-9.82, acceleration.y.value, 1e-3);
+
+        let potential = point.potential(surface);
+        assert_approx_eq!(-6.2565e7, potential.value, 1e-3);
+        assert_approx_eq!(2.0 * potential.value, point.potential_energy(Mass::in_kilograms(2.0), surface).value);
+
+        assert_eq!(Acceleration::zero(), point.acceleration(Position::zero()));
+    }
+
+    #[test]
+    fn uniform_field() {
+        let field = GravityField::Uniform { acceleration: Acceleration::in_meters_per_second_squared(0.0, -9.8) };
+        let mass = Mass::in_kilograms(3.0);
+        let low = field.potential_energy(mass, Position::in_meters(5.0, 1.0));
+        let high = field.potential_energy(mass, Position::in_meters(-2.0, 11.0));
+
+        assert_approx_eq!(3.0 * 9.8 * 10.0, (high - low).value);
+        assert_eq!(Acceleration::in_meters_per_second_squared(0.0, -9.8), field.acceleration(Position::in_meters(1e9, 0.0)));
+    }
+
+    #[test]
+    fn layered_matches_point_mass_outside() {
+        let (point, layered) = uniform_planet();
+
+        for &distance in &[RADIUS, 2.0 * RADIUS, 60.0 * RADIUS] {
+            let position = Position::in_meters(distance * 0.6, -distance * 0.8);
+
+            assert_approx_eq!(point.potential(position).value, layered.potential(position).value);
+            assert!((point.acceleration(position) - layered.acceleration(position)).magnitude().value < 1e-9);
+        }
+    }
+
+    #[test]
+    fn layered_interior() {
+        let (point, layered) = uniform_planet();
+        let surface_gravity = point.acceleration(Position::in_meters(RADIUS, 0.0)).x.value;
+        let surface_potential = point.potential(Position::in_meters(RADIUS, 0.0)).value;
+
+        // inside a uniform sphere gravity falls linearly to zero at the center
+        let halfway = layered.acceleration(Position::in_meters(RADIUS / 2.0, 0.0));
+        assert_approx_eq!(surface_gravity / 2.0, halfway.x.value);
+        assert_eq!(Acceleration::zero(), layered.acceleration(Position::zero()));
+
+        assert_approx_eq!(1.5 * surface_potential, layered.potential(Position::zero()).value);
+        assert_approx_eq!(1.375 * surface_potential, layered.potential(Position::in_meters(0.0, RADIUS / 2.0)).value);
+    }
+
+    #[test]
+    fn dense_core_pulls_harder_inside() {
+        let radius = Length::in_meters(RADIUS);
+        let layers = vec![
+            Layer { outer_radius: radius * 0.5, density: Density::in_kilograms_per_meter_cubed(11_000.0) },
+            Layer { outer_radius: radius, density: Density::in_kilograms_per_meter_cubed(4_400.0) },
+        ];
+        let mass = Density::in_kilograms_per_meter_cubed(11_000.0) * sphere_volume(radius * 0.5)
+            + Density::in_kilograms_per_meter_cubed(4_400.0) * (sphere_volume(radius) - sphere_volume(radius * 0.5));
+
+        let layered = GravityField::Layered { center: Position::zero(), layers };
+        let point = GravityField::Point { mass, position: Position::zero() };
+
+        let surface = Position::in_meters(RADIUS, 0.0);
+        assert_approx_eq!(point.acceleration(surface).x.value, layered.acceleration(surface).x.value);
+        assert_approx_eq!(point.potential(surface).value, layered.potential(surface).value);
+
+        // at the core boundary gravity is stronger than at the surface
+        let boundary = layered.acceleration(Position::in_meters(RADIUS * 0.5, 0.0));
+        assert!(boundary.x < layered.acceleration(surface).x);
+    }
+
+    #[test]
+    fn speeds_and_radii() {
+        let earth = Mass::in_kilograms(EARTH);
+        let sun = Mass::in_kilograms(SUN);
+        let radius = Length::in_meters(RADIUS);
+        let au = Length::in_meters(1.496e11);
+
+        assert_approx_eq!(11_186.0, escape_speed(earth, radius).value, 1e-3);
+        assert_approx_eq!(escape_speed(earth, radius).value / 2.0_f64.sqrt(), circular_speed(earth, radius).value);
+
+        assert_approx_eq!(9.25e8, sphere_of_influence(au, earth, sun).value, 1e-2);
+        assert_approx_eq!(1.4966e9, hill_radius(au, 0.0, earth, sun).value, 1e-3);
+        assert!(hill_radius(au, 0.0167, earth, sun) < hill_radius(au, 0.0, earth, sun));
+    }
+}
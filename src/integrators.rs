@@ -0,0 +1,185 @@
+use crate::*;
+
+/// The position and velocity of a point mass.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct State<F = Unframed> {
+    pub position: Vector<Meters, F>,
+    pub velocity: Vector<MetersPerSecond, F>,
+}
+
+impl<F: Frame> State<F> {
+    #[inline]
+    pub fn new(position: Vector<Meters, F>, velocity: Vector<MetersPerSecond, F>) -> Self {
+        State { position, velocity }
+    }
+
+    /// The state after moving at `velocity` and accelerating at `acceleration` for `dt`.
+    #[inline]
+    fn advanced(&self, velocity: Vector<MetersPerSecond, F>, acceleration: Vector<MetersPerSecondSquared, F>, dt: Time) -> Self {
+        State {
+            position: self.position + velocity * dt,
+            velocity: self.velocity + acceleration * dt,
+        }
+    }
+}
+
+/// A scheme for stepping a [`State`] forward under an acceleration.
+///
+/// The acceleration is given the time and state at which it is evaluated. The Verlet and leapfrog
+/// schemes are symplectic, so for forces that depend only on position their energy error stays
+/// bounded over long runs. The velocity passed to them part-way through a step is approximate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Integrator {
+    /// First order, with position and velocity both updated from the start of the step. Gains energy.
+    ExplicitEuler,
+    /// First order, updating velocity first and moving with the new velocity.
+    SemiImplicitEuler,
+    /// Second order kick-drift-kick, evaluating the acceleration at both ends of the step.
+    VelocityVerlet,
+    /// Second order drift-kick-drift, evaluating the acceleration at the midpoint.
+    Leapfrog,
+    /// Classic fourth order Runge-Kutta. Accurate, but slowly loses energy.
+    RungeKutta4,
+}
+
+impl Integrator {
+    /// Advances `state` from `time` by `dt`.
+    pub fn step<F, A>(&self, state: State<F>, time: Time, dt: Time, acceleration: A) -> State<F>
+        where
+            F: Frame,
+            A: Fn(Time, &State<F>) -> Vector<MetersPerSecondSquared, F>,
+    {
+        let half = dt / 2.0;
+
+        match self {
+            Integrator::ExplicitEuler => {
+                state.advanced(state.velocity, acceleration(time, &state), dt)
+            }
+            Integrator::SemiImplicitEuler => {
+                let velocity = state.velocity + acceleration(time, &state) * dt;
+                State::new(state.position + velocity * dt, velocity)
+            }
+            Integrator::VelocityVerlet => {
+                let velocity = state.velocity + acceleration(time, &state) * half;
+                let moved = State::new(state.position + velocity * dt, velocity);
+
+                State::new(moved.position, velocity + acceleration(time + dt, &moved) * half)
+            }
+            Integrator::Leapfrog => {
+                let midpoint = State::new(state.position + state.velocity * half, state.velocity);
+                let velocity = state.velocity + acceleration(time + half, &midpoint) * dt;
+
+                State::new(midpoint.position + velocity * half, velocity)
+            }
+            Integrator::RungeKutta4 => {
+                let a1 = acceleration(time, &state);
+                let s2 = state.advanced(state.velocity, a1, half);
+                let a2 = acceleration(time + half, &s2);
+                let s3 = state.advanced(s2.velocity, a2, half);
+                let a3 = acceleration(time + half, &s3);
+                let s4 = state.advanced(s3.velocity, a3, dt);
+                let a4 = acceleration(time + dt, &s4);
+
+                let velocity = (state.velocity + s2.velocity * 2.0 + s3.velocity * 2.0 + s4.velocity) / 6.0;
+                let acceleration = (a1 + a2 * 2.0 + a3 * 2.0 + a4) / 6.0;
+
+                state.advanced(velocity, acceleration, dt)
+            }
+        }
+    }
+
+    /// Takes `steps` fixed steps of `dt` starting at `time`.
+    pub fn integrate<F, A>(&self, state: State<F>, time: Time, dt: Time, steps: usize, acceleration: A) -> State<F>
+        where
+            F: Frame,
+            A: Fn(Time, &State<F>) -> Vector<MetersPerSecondSquared, F>,
+    {
+        (0..steps).fold(state, |state, i| {
+            self.step(state, time + dt * i as Float, dt, &acceleration)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// A unit mass on a spring with an angular frequency of 1 rad/s.
+    fn spring(_: Time, state: &State) -> Acceleration {
+        let tau = Time::in_seconds(1.0);
+        -state.position / tau / tau
+    }
+
+    fn start() -> State {
+        State::new(Position::in_meters(1.0, 0.0), Velocity::zero())
+    }
+
+    /// Energy per unit mass, in J/kg.
+    fn energy(state: &State) -> Float {
+        (state.velocity.magnitude_squared() + state.position.magnitude_squared()) / 2.0
+    }
+
+    /// The relative energy change after a hundred periods.
+    fn energy_drift(integrator: Integrator) -> Float {
+        let dt = Time::in_seconds(0.05);
+        let steps = (200.0 * PI / dt.value).round() as usize;
+        let end = integrator.integrate(start(), Time::zero(), dt, steps, spring);
+
+        energy(&end) / energy(&start()) - 1.0
+    }
+
+    #[test]
+    fn explicit_euler_gains_energy() {
+        assert!(energy_drift(Integrator::ExplicitEuler) > 10.0);
+    }
+
+    #[test]
+    fn symplectic_integrators_keep_energy_bounded() {
+        assert!(energy_drift(Integrator::SemiImplicitEuler).abs() < 0.03);
+        assert!(energy_drift(Integrator::VelocityVerlet).abs() < 1e-3);
+        assert!(energy_drift(Integrator::Leapfrog).abs() < 1e-3);
+    }
+
+    #[test]
+    fn runge_kutta_slowly_loses_energy() {
+        let drift = energy_drift(Integrator::RungeKutta4);
+
+        assert!(drift < 0.0);
+        assert!(drift > -1e-5);
+    }
+
+    #[test]
+    fn runge_kutta_tracks_the_exact_solution() {
+        let dt = Time::in_seconds(0.01);
+        let end = Integrator::RungeKutta4.integrate(start(), Time::zero(), dt, 1000, spring);
+
+        assert_approx_eq!(10.0_f64.cos(), end.position.x.value, 1e-8);
+        assert_approx_eq!(-10.0_f64.sin(), end.velocity.x.value, 1e-8);
+    }
+
+    #[test]
+    fn second_order_schemes_are_exact_under_constant_gravity() {
+        let gravity = |_: Time, _: &State| Acceleration::in_meters_per_second_squared(0.0, -9.8);
+        let launch = State::new(Position::zero(), Velocity::in_meters_per_second(3.0, 20.0));
+        let dt = Time::in_seconds(0.1);
+
+        for integrator in [Integrator::VelocityVerlet, Integrator::Leapfrog, Integrator::RungeKutta4].iter() {
+            let end = integrator.integrate(launch, Time::zero(), dt, 20, gravity);
+
+            assert_approx_eq!(6.0, end.position.x.value);
+            assert_approx_eq!(40.0 - 4.9 * 4.0, end.position.y.value);
+            assert_approx_eq!(20.0 - 19.6, end.velocity.y.value);
+        }
+    }
+
+    #[test]
+    fn time_dependent_acceleration() {
+        // a = t, so v = t² / 2 and x = t³ / 6
+        let ramp = |time: Time, _: &State| Acceleration::in_meters_per_second_squared(time.value, 0.0);
+        let end = Integrator::RungeKutta4.integrate(State::default(), Time::zero(), Time::in_seconds(0.5), 4, ramp);
+
+        assert_approx_eq!(2.0, end.velocity.x.value);
+        assert_approx_eq!(8.0 / 6.0, end.position.x.value);
+    }
+}
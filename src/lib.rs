@@ -1,12 +1,106 @@
 pub use units::*;
-use scalars::Scalar;
-use vectors::Vector;
+pub use frames::*;
+pub use scalars::Scalar;
+pub use vectors::Vector;
 pub use types::*;
+pub use transform::Transform2;
+pub use matrices::*;
+pub use vectors::Vector3;
+pub use points::Point;
+pub use camera::{Camera2, CameraAnimation};
+pub use pixels::*;
+pub use aabb::Aabb;
+pub use geometry::*;
+pub use mass::*;
+pub use collision::*;
+pub use contact::*;
+pub use spatial::*;
+pub use broad_phase::SortAndSweep;
+pub use integrators::{State, Integrator};
+pub use ode::{OdeState, DormandPrince, Solution};
+pub use nbody::*;
+pub use orbit::Orbit;
+pub use maneuver::*;
+pub use rocket::*;
+pub use catalog::*;
+pub use constants::*;
+pub use gravity::*;
+pub use atmosphere::*;
+pub use ballistics::*;
+pub use pursuit::*;
+
+#[cfg(test)]
+macro_rules! assert_approx_eq {
+    ($expected:expr, $actual:expr) => (
+        assert_approx_eq!($expected, $actual, 1e-9)
+    );
+    ($expected:expr, $actual:expr, $epsilon:expr) => ({
+        let (expected, actual): (Float, Float) = ($expected, $actual);
+        assert!(
+            (expected - actual).abs() <= $epsilon * expected.abs().max(1.0),
+            "expected {}, got {}", expected, actual
+        );
+    });
+}
+
+/// Shorthand shared by the unit tests.
+#[cfg(test)]
+mod test_helpers {
+    use crate::*;
+
+    pub fn p(x: Float, y: Float) -> Position {
+        Position::in_meters(x, y)
+    }
+
+    pub fn v(x: Float, y: Float) -> Velocity {
+        Velocity::in_meters_per_second(x, y)
+    }
+
+    /// Repeatable pseudo-random numbers in [0, 1), from a linear congruential generator.
+    pub fn random(seed: u64) -> impl FnMut() -> Float {
+        let mut state = seed;
+        move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as Float / (1_u64 << 53) as Float
+        }
+    }
+
+    /// Scattered but repeatable positions in a square with sides of `size` meters.
+    pub fn scattered(count: usize, size: Float) -> Vec<Position> {
+        let mut next = random(12345);
+        (0..count).map(|_| p(next() * size, next() * size)).collect()
+    }
+}
 
 mod units;
+mod frames;
 mod scalars;
 mod vectors;
 mod conversion;
+mod points;
 mod types;
+mod transform;
+mod matrices;
+mod camera;
+mod pixels;
+mod aabb;
+mod geometry;
+mod mass;
+mod collision;
+mod contact;
+mod spatial;
+mod broad_phase;
+mod integrators;
+mod ode;
+mod nbody;
+mod orbit;
+mod maneuver;
+mod rocket;
+mod catalog;
+mod constants;
+mod gravity;
+mod atmosphere;
+mod ballistics;
+mod pursuit;
 
 pub type Float = f64;
\ No newline at end of file
@@ -0,0 +1,429 @@
+use std::f64::consts::PI;
+use crate::*;
+
+/// An instantaneous change in speed along the direction of motion.
+///
+/// The time and angle are measured from the first burn of the transfer, with the angle in the
+/// direction of motion.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Burn {
+    pub time: Time,
+    pub angle: Angle,
+    pub radius: Length,
+    pub delta_v: Speed,
+}
+
+impl Burn {
+    /// Where the burn happens for an orbit whose first burn is at `departure`, counter-clockwise
+    /// when `prograde` and clockwise otherwise.
+    #[inline]
+    pub fn position(&self, departure: Angle, prograde: bool) -> Position {
+        let angle = if prograde { departure + self.angle } else { departure - self.angle };
+        UnitVector::from((angle.cos(), angle.sin())) * self.radius
+    }
+}
+
+/// A sequence of burns between two circular orbits around the same body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    pub burns: Vec<Burn>,
+}
+
+impl Transfer {
+    /// The time from the first burn to the last.
+    #[inline]
+    pub fn duration(&self) -> Time {
+        self.burns.last().map(|burn| burn.time).unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn total_delta_v(&self) -> Speed {
+        self.burns.iter().fold(Speed::zero(), |total, burn| total + burn.delta_v)
+    }
+}
+
+/// The transfer between circular orbits of radius `from` and `to` using a single ellipse.
+pub fn hohmann(central_mass: Mass, from: Length, to: Length) -> Transfer {
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    let semi_major_axis = (from + to) / 2.0;
+
+    Transfer {
+        burns: vec![
+            Burn {
+                time: Time::zero(),
+                angle: Angle::zero(),
+                radius: from,
+                delta_v: speed_change(mu, from, from, semi_major_axis),
+            },
+            Burn {
+                time: half_period(mu, semi_major_axis),
+                angle: Angle::in_radians(PI),
+                radius: to,
+                delta_v: speed_change(mu, to, semi_major_axis, to),
+            },
+        ]
+    }
+}
+
+/// The transfer between circular orbits of radius `from` and `to` using two half ellipses
+/// that meet at `apoapsis`.
+///
+/// This takes less delta-v than a Hohmann transfer when the ratio of the radii is large.
+pub fn bi_elliptic(central_mass: Mass, from: Length, to: Length, apoapsis: Length) -> Transfer {
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    let first = (from + apoapsis) / 2.0;
+    let second = (to + apoapsis) / 2.0;
+    let midway = half_period(mu, first);
+
+    Transfer {
+        burns: vec![
+            Burn {
+                time: Time::zero(),
+                angle: Angle::zero(),
+                radius: from,
+                delta_v: speed_change(mu, from, from, first),
+            },
+            Burn {
+                time: midway,
+                angle: Angle::in_radians(PI),
+                radius: apoapsis,
+                delta_v: speed_change(mu, apoapsis, first, second),
+            },
+            Burn {
+                time: midway + half_period(mu, second),
+                angle: Angle::in_radians(2.0 * PI),
+                radius: to,
+                delta_v: speed_change(mu, to, second, to),
+            },
+        ]
+    }
+}
+
+/// The burns that move a craft `phase` ahead along its circular orbit of `radius` by
+/// spending `revolutions` on a phasing orbit. A negative phase falls behind.
+///
+/// Returns `None` if the phasing orbit would pass through the center of the body.
+pub fn phasing(central_mass: Mass, radius: Length, phase: Angle, revolutions: u32) -> Option<Transfer> {
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    let revolutions = revolutions.max(1) as Float;
+
+    // catching up means completing the orbit sooner, on a smaller ellipse
+    let period = half_period(mu, radius) * 2.0 * (1.0 - phase.value / (2.0 * PI * revolutions));
+    if period <= Time::zero() {
+        return None;
+    }
+
+    // by Kepler's third law the semi-major axis scales with the period to the power of 2/3
+    let semi_major_axis = radius * (period / (half_period(mu, radius) * 2.0)).powf(2.0 / 3.0);
+    if semi_major_axis * 2.0 <= radius {
+        return None;
+    }
+
+    let delta_v = speed_change(mu, radius, radius, semi_major_axis);
+
+    Some(Transfer {
+        burns: vec![
+            Burn { time: Time::zero(), angle: Angle::zero(), radius, delta_v },
+            Burn { time: period * revolutions, angle: Angle::in_radians(2.0 * PI * revolutions), radius, delta_v },
+        ]
+    })
+}
+
+/// The time between repeated alignments of two circular orbits, or `None` if they have the same radius.
+pub fn synodic_period(central_mass: Mass, from: Length, to: Length) -> Option<Time> {
+    let rate = relative_angular_speed(central_mass, from, to);
+
+    if rate == Scalar::zero() {
+        None
+    } else {
+        Some(Scalar::<Float>::from(2.0 * PI) / rate.abs())
+    }
+}
+
+/// The wait before starting a Hohmann transfer from radius `from` that meets a target at radius `to`,
+/// where the target is currently `phase` ahead of the craft.
+///
+/// Returns `None` if the orbits have the same radius and so never change alignment.
+pub fn rendezvous_wait(central_mass: Mass, from: Length, to: Length, phase: Angle) -> Option<Time> {
+    let rate = relative_angular_speed(central_mass, from, to);
+    if rate == Scalar::zero() {
+        return None;
+    }
+
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    let required = PI - (mean_motion(mu, to) * half_period(mu, (from + to) / 2.0)).value;
+
+    // the phase changes at `rate`, so wait for it to reach the required lead
+    let gap = if rate > Scalar::zero() { required - phase.value } else { phase.value - required };
+    Some(Scalar::<Float>::from(gap.rem_euclid(2.0 * PI)) / rate.abs())
+}
+
+/// The delta-v to rotate a velocity of `speed` through `angle` without changing its magnitude.
+#[inline]
+pub fn plane_change(speed: Speed, angle: Angle) -> Speed {
+    speed * (2.0 * (angle.value / 2.0).sin().abs())
+}
+
+/// Solves Lambert's problem, finding the velocities at `departure` and `arrival` of the conic that
+/// joins them in `time_of_flight` without completing a revolution.
+///
+/// Returns `None` if the positions are at the center, are directly opposite each other or
+/// in the same direction, or if the time of flight is not positive.
+pub fn lambert(
+    central_mass: Mass,
+    departure: Position,
+    arrival: Position,
+    time_of_flight: Time,
+    prograde: bool,
+) -> Option<(Velocity, Velocity)> {
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    let r1 = departure.magnitude();
+    let r2 = arrival.magnitude();
+
+    if r1 == Length::zero() || r2 == Length::zero() || time_of_flight <= Time::zero() {
+        return None;
+    }
+
+    let cross: Area = departure.cross(arrival);
+    let cos = departure.dot(arrival) / (r1 * r2);
+    let mut angle = cos.clamp(-1.0, 1.0).acos();
+    if (cross < Area::zero()) == prograde {
+        angle = 2.0 * PI - angle;
+    }
+
+    if angle.sin().abs() < 1e-10 {
+        return None;
+    }
+
+    // universal variable formulation, with z the square of the change in eccentric anomaly
+    let a = (r1 * r2 / (1.0 - angle.cos())).sqrt() * angle.sin();
+    let y = |z: Float| r1 + r2 + a * ((z * stumpff_s(z) - 1.0) / stumpff_c(z).sqrt());
+    let flight_time = |z: Float| {
+        let y = y(z);
+        if y < Length::zero() {
+            Time::from(Float::NEG_INFINITY)
+        } else {
+            // x^1.5 / sqrt(μ) is written as x / sqrt(μ / x) to keep the units
+            let x = y / stumpff_c(z);
+            x / (mu / x).sqrt() * stumpff_s(z) + a / (mu / y).sqrt()
+        }
+    };
+
+    // the time of flight increases with z, so bracket the root and bisect
+    let mut high = 4.0 * PI * PI;
+    let mut low = -4.0 * PI * PI;
+    while flight_time(low) > time_of_flight {
+        low *= 2.0;
+        if low < -1e6 {
+            return None;
+        }
+    }
+
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        if flight_time(middle) < time_of_flight {
+            low = middle;
+        } else {
+            high = middle;
+        }
+
+        if high - low <= 1e-15 * high.abs().max(1.0) {
+            break;
+        }
+    }
+
+    let y = y((low + high) / 2.0);
+    let f = 1.0 - y / r1;
+    let g = a / (mu / y).sqrt();
+    let g_dot = 1.0 - y / r2;
+
+    let departure_velocity = (arrival - departure * f) / g;
+    let arrival_velocity = (arrival * g_dot - departure) / g;
+
+    Some((departure_velocity, arrival_velocity))
+}
+
+/// The change in speed at `radius` between orbits with semi-major axes `before` and `after`.
+fn speed_change(mu: GravitationalParameter, radius: Length, before: Length, after: Length) -> Speed {
+    (orbital_speed(mu, radius, after) - orbital_speed(mu, radius, before)).abs()
+}
+
+/// The vis-viva equation.
+fn orbital_speed(mu: GravitationalParameter, radius: Length, semi_major_axis: Length) -> Speed {
+    (mu / radius * 2.0 - mu / semi_major_axis).sqrt()
+}
+
+fn half_period(mu: GravitationalParameter, semi_major_axis: Length) -> Time {
+    semi_major_axis / (mu / semi_major_axis).sqrt() * PI
+}
+
+/// The angular speed of a circular orbit at `radius`.
+fn mean_motion(mu: GravitationalParameter, radius: Length) -> Scalar<PerSecond> {
+    (mu / radius).sqrt() / radius
+}
+
+/// How quickly an orbit at `to` gains angle on one at `from`.
+fn relative_angular_speed(central_mass: Mass, from: Length, to: Length) -> Scalar<PerSecond> {
+    let mu = GRAVITATIONAL_CONSTANT * central_mass;
+    mean_motion(mu, to) - mean_motion(mu, from)
+}
+
+fn stumpff_c(z: Float) -> Float {
+    if z.abs() < 1e-3 {
+        0.5 - z / 24.0 + z * z / 720.0
+    } else if z > 0.0 {
+        (1.0 - z.sqrt().cos()) / z
+    } else {
+        ((-z).sqrt().cosh() - 1.0) / -z
+    }
+}
+
+fn stumpff_s(z: Float) -> Float {
+    if z.abs() < 1e-3 {
+        1.0 / 6.0 - z / 120.0 + z * z / 5040.0
+    } else if z > 0.0 {
+        let root = z.sqrt();
+        (root - root.sin()) / root.powi(3)
+    } else {
+        let root = (-z).sqrt();
+        (root.sinh() - root) / root.powi(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn earth() -> Mass {
+        Mass::in_kilograms(5.972_2e24)
+    }
+
+    fn km(value: Float) -> Length {
+        Length::in_meters(value * 1e3)
+    }
+
+    #[test]
+    fn hohmann_to_geostationary() {
+        let transfer = hohmann(earth(), km(6678.0), km(42164.0));
+
+        assert_eq!(2, transfer.burns.len());
+        assert_approx_eq!(2425.0, transfer.burns[0].delta_v.value, 1e-3);
+        assert_approx_eq!(1467.0, transfer.burns[1].delta_v.value, 1e-3);
+        assert_approx_eq!(5.275, transfer.duration().value / 3600.0, 1e-3);
+
+        let arrival = transfer.burns[1].position(Angle::zero(), true);
+        assert_approx_eq!(-42164e3, arrival.x.value);
+        assert!(arrival.y.value.abs() < 1e-6);
+
+        // a quarter of the way around lands on opposite sides depending on the direction
+        let quarter = Burn { angle: Angle::in_degrees(90.0), ..transfer.burns[1] };
+        assert_approx_eq!(42164e3, quarter.position(Angle::zero(), true).y.value);
+        assert_approx_eq!(-42164e3, quarter.position(Angle::zero(), false).y.value);
+
+        // lowering takes the same burns in reverse
+        let back = hohmann(earth(), km(42164.0), km(6678.0));
+        assert_approx_eq!(transfer.total_delta_v().value, back.total_delta_v().value);
+    }
+
+    #[test]
+    fn bi_elliptic_beats_hohmann_for_large_ratios() {
+        let from = km(7000.0);
+        let to = from * 20.0;
+        let direct = hohmann(earth(), from, to);
+        let indirect = bi_elliptic(earth(), from, to, from * 40.0);
+
+        assert_eq!(3, indirect.burns.len());
+        assert!(indirect.total_delta_v() < direct.total_delta_v());
+        assert!(indirect.duration() > direct.duration());
+
+        // with the intermediate apoapsis at the target it matches a Hohmann transfer
+        let degenerate = bi_elliptic(earth(), from, to, to);
+        assert_approx_eq!(direct.total_delta_v().value, degenerate.total_delta_v().value);
+    }
+
+    #[test]
+    fn phasing_orbit_moves_ahead() {
+        let radius = km(7000.0);
+        let phase = Angle::in_degrees(30.0);
+        let transfer = phasing(earth(), radius, phase, 2).unwrap();
+
+        // a target on the original orbit travels the full two revolutions plus the phase in that time
+        let period = half_period(GRAVITATIONAL_CONSTANT * earth(), radius) * 2.0;
+        assert_approx_eq!(2.0 - 30.0 / 360.0, transfer.duration() / period);
+        assert_eq!(transfer.burns[0].delta_v, transfer.burns[1].delta_v);
+
+        let behind = phasing(earth(), radius, -phase, 2).unwrap();
+        assert!(behind.duration() > period * 2.0);
+
+        assert_eq!(None, phasing(earth(), radius, Angle::in_degrees(400.0), 1));
+    }
+
+    #[test]
+    fn rendezvous_arrives_at_target() {
+        let mu = GRAVITATIONAL_CONSTANT * earth();
+        let phase = Angle::in_degrees(10.0);
+
+        // raising and lowering, where the target falls behind and pulls ahead respectively
+        for &(from, to) in &[(km(7000.0), km(12000.0)), (km(12000.0), km(7000.0))] {
+            let wait = rendezvous_wait(earth(), from, to, phase).unwrap();
+            let transfer = hohmann(earth(), from, to);
+            let arrival = wait + transfer.duration();
+
+            let chaser_rate = (mu.value / from.value.powi(3)).sqrt();
+            let target_rate = (mu.value / to.value.powi(3)).sqrt();
+            let chaser_angle = chaser_rate * wait.value + PI;
+            let target_angle = phase.value + target_rate * arrival.value;
+
+            assert!(wait >= Time::zero());
+            assert!(wait < synodic_period(earth(), from, to).unwrap());
+            assert_approx_eq!(0.0, (target_angle - chaser_angle).sin(), 1e-9);
+            assert!((target_angle - chaser_angle).cos() > 0.0);
+        }
+
+        assert_eq!(None, rendezvous_wait(earth(), km(7000.0), km(7000.0), phase));
+    }
+
+    #[test]
+    fn plane_change_cost() {
+        let speed = Speed::in_meters_per_second(7500.0);
+
+        assert_eq!(Speed::zero(), plane_change(speed, Angle::zero()));
+        assert_approx_eq!(7500.0, plane_change(speed, Angle::in_degrees(60.0)).value);
+        assert_approx_eq!(15000.0, plane_change(speed, Angle::in_degrees(180.0)).value);
+    }
+
+    #[test]
+    fn lambert_recovers_orbit_velocities() {
+        let orbits = [
+            (Orbit::from_periapsis(earth(), km(7000.0), 0.4, Angle::in_degrees(20.0), Time::zero()), 0.0, 2500.0),
+            (Orbit::from_periapsis(earth(), km(7000.0), 0.0, Angle::zero(), Time::zero()), 100.0, 3000.0),
+            (Orbit::from_periapsis(earth(), km(7000.0), 1.5, Angle::in_degrees(-50.0), Time::zero()), -1500.0, 2000.0),
+        ];
+
+        for &(mut orbit, start, end) in orbits.iter() {
+            for &retrograde in &[false, true] {
+                if retrograde {
+                    orbit.inclination = Angle::in_radians(PI);
+                }
+
+                let (t1, t2) = (Time::in_seconds(start), Time::in_seconds(end));
+                let (r1, v1) = orbit.state_at(t1);
+                let (r2, v2) = orbit.state_at(t2);
+                let (departure, arrival) = lambert(earth(), r1, r2, t2 - t1, !retrograde).unwrap();
+
+                assert!((departure - v1).magnitude().value < 1e-6 * v1.magnitude().value, "{:?} {:?}", departure, v1);
+                assert!((arrival - v2).magnitude().value < 1e-6 * v2.magnitude().value, "{:?} {:?}", arrival, v2);
+            }
+        }
+    }
+
+    #[test]
+    fn lambert_rejects_degenerate_geometry() {
+        let r1 = Position::in_meters(7e6, 0.0);
+        let flight = Time::in_seconds(1000.0);
+
+        assert_eq!(None, lambert(earth(), r1, Position::in_meters(-8e6, 0.0), flight, true));
+        assert_eq!(None, lambert(earth(), r1, Position::in_meters(8e6, 0.0), flight, true));
+        assert_eq!(None, lambert(earth(), r1, Position::in_meters(0.0, 8e6), Time::zero(), true));
+    }
+}
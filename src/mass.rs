@@ -0,0 +1,262 @@
+use std::f64::consts::PI;
+use crate::*;
+
+/// The mass, center of mass and moment of inertia of a flat body.
+///
+/// The moment of inertia is about the axis through the center of mass,
+/// perpendicular to the plane.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct MassProperties<F = Unframed> {
+    pub mass: Mass,
+    pub center_of_mass: Vector<Meters, F>,
+    pub moment_of_inertia: MomentOfInertia,
+}
+
+impl<F: Frame> MassProperties<F> {
+    /// The moment of inertia about a parallel axis through `point`.
+    #[inline]
+    pub fn moment_of_inertia_about(&self, point: Vector<Meters, F>) -> MomentOfInertia {
+        let offset = point - self.center_of_mass;
+        self.moment_of_inertia + self.mass * offset.dot(offset)
+    }
+
+    /// The combined properties of two bodies.
+    pub fn combine(&self, other: &Self) -> Self {
+        let mass = self.mass + other.mass;
+
+        if mass == Mass::zero() {
+            return MassProperties { mass, ..*self };
+        }
+
+        let center_of_mass = self.center_of_mass * (self.mass / mass)
+            + other.center_of_mass * (other.mass / mass);
+
+        MassProperties {
+            mass,
+            center_of_mass,
+            moment_of_inertia: self.moment_of_inertia_about(center_of_mass)
+                + other.moment_of_inertia_about(center_of_mass),
+        }
+    }
+}
+
+impl<F: Frame> Circle<Meters, F> {
+    pub fn mass_properties(&self, density: ArealDensity) -> MassProperties<F> {
+        let mass = density * self.area();
+
+        MassProperties {
+            mass,
+            center_of_mass: self.center,
+            moment_of_inertia: mass * (self.radius * self.radius) / 2.0,
+        }
+    }
+}
+
+impl<F: Frame> Aabb<Meters, F> {
+    pub fn mass_properties(&self, density: ArealDensity) -> MassProperties<F> {
+        let mass = density * self.area();
+        let size = self.size();
+
+        MassProperties {
+            mass,
+            center_of_mass: self.center(),
+            moment_of_inertia: mass * size.dot(size) / 12.0,
+        }
+    }
+}
+
+impl<F: Frame> Polygon<Meters, F> {
+    /// Returns `None` if the polygon has no area.
+    pub fn mass_properties(&self, density: ArealDensity) -> Option<MassProperties<F>> {
+        // work relative to the first vertex, so that a polygon far from the origin keeps its precision
+        let origin = *self.vertices.first()?;
+        let local = Polygon::new(self.vertices.iter().map(|&vertex| vertex - origin).collect());
+
+        let signed_area = local.signed_area();
+        let centroid = local.centroid()?;
+
+        // second moment of area about the first vertex, with the sign of the winding
+        let second_moment = local.edges().fold(Scalar::<MetersToTheFourth>::zero(), |sum, edge| {
+            let (a, b) = (edge.start, edge.end);
+            sum + a.cross(b) * (a.dot(a) + a.dot(b) + b.dot(b))
+        }) / 12.0;
+
+        let second_moment = if signed_area < Area::zero() { -second_moment } else { second_moment };
+        let mass = density * signed_area.abs();
+
+        Some(MassProperties {
+            mass,
+            center_of_mass: origin + centroid,
+            moment_of_inertia: density * second_moment - mass * centroid.dot(centroid),
+        })
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Sphere {
+    pub radius: Length,
+}
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Cuboid {
+    pub width: Length,
+    pub height: Length,
+    pub depth: Length,
+}
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Cylinder {
+    pub radius: Length,
+    pub height: Length,
+}
+
+impl Sphere {
+    #[inline]
+    pub fn volume(&self) -> Volume {
+        self.radius * self.radius * self.radius * (4.0 / 3.0 * PI)
+    }
+
+    #[inline]
+    pub fn mass(&self, density: Density) -> Mass {
+        density * self.volume()
+    }
+}
+
+impl Cuboid {
+    #[inline]
+    pub fn volume(&self) -> Volume {
+        self.width * self.height * self.depth
+    }
+
+    #[inline]
+    pub fn mass(&self, density: Density) -> Mass {
+        density * self.volume()
+    }
+}
+
+impl Cylinder {
+    #[inline]
+    pub fn volume(&self) -> Volume {
+        self.radius * self.radius * self.height * PI
+    }
+
+    #[inline]
+    pub fn mass(&self, density: Density) -> Mass {
+        density * self.volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::p;
+
+    fn density() -> ArealDensity {
+        ArealDensity::in_kilograms_per_meter_squared(2.0)
+    }
+
+    #[test]
+    fn circle_mass_properties() {
+        let circle = Circle::new(p(1.0, 2.0), Length::in_meters(3.0));
+        let properties = circle.mass_properties(density());
+
+        assert_approx_eq!(18.0 * PI, properties.mass.value);
+        assert_eq!(p(1.0, 2.0), properties.center_of_mass);
+        assert_approx_eq!(81.0 * PI, properties.moment_of_inertia.value);
+    }
+
+    #[test]
+    fn box_mass_properties() {
+        let aabb = Aabb::new(p(0.0, 0.0), p(3.0, 4.0));
+        let properties = aabb.mass_properties(density());
+
+        assert_eq!(Mass::in_kilograms(24.0), properties.mass);
+        assert_eq!(p(1.5, 2.0), properties.center_of_mass);
+        assert_eq!(MomentOfInertia::in_kilogram_meters_squared(50.0), properties.moment_of_inertia);
+    }
+
+    #[test]
+    fn polygon_matches_box() {
+        let aabb = Aabb::new(p(1.0, 1.0), p(4.0, 5.0));
+        let expected = aabb.mass_properties(density());
+
+        for vertices in [aabb.corners().to_vec(), aabb.corners().iter().rev().copied().collect()].iter() {
+            let properties = Polygon::new(vertices.clone()).mass_properties(density()).unwrap();
+
+            assert_approx_eq!(expected.mass.value, properties.mass.value);
+            assert_approx_eq!(expected.center_of_mass.x.value, properties.center_of_mass.x.value);
+            assert_approx_eq!(expected.center_of_mass.y.value, properties.center_of_mass.y.value);
+            assert_approx_eq!(expected.moment_of_inertia.value, properties.moment_of_inertia.value);
+        }
+    }
+
+    #[test]
+    fn triangle_mass_properties() {
+        // right triangle with legs of 3 m: I = m (a² + b²) / 18 about the centroid
+        let triangle = Polygon::new(vec![p(0.0, 0.0), p(3.0, 0.0), p(0.0, 3.0)]);
+        let properties = triangle.mass_properties(density()).unwrap();
+
+        assert_approx_eq!(9.0, properties.mass.value);
+        assert_eq!(p(1.0, 1.0), properties.center_of_mass);
+        assert_approx_eq!(9.0, properties.moment_of_inertia.value);
+    }
+
+    #[test]
+    fn polygon_far_from_origin() {
+        // a unit square with unit density has I = 1/6 about its center, wherever it is
+        for &offset in &[0.0, 1e4, 1e6, 1e7] {
+            let square = Polygon::new(vec![p(offset, offset), p(offset + 1.0, offset), p(offset + 1.0, offset + 1.0), p(offset, offset + 1.0)]);
+            let properties = square.mass_properties(ArealDensity::in_kilograms_per_meter_squared(1.0)).unwrap();
+
+            assert_approx_eq!(1.0, properties.mass.value);
+            assert_approx_eq!(offset + 0.5, properties.center_of_mass.x.value);
+            assert_approx_eq!(1.0 / 6.0, properties.moment_of_inertia.value);
+        }
+    }
+
+    #[test]
+    fn degenerate_polygon_has_no_mass_properties() {
+        let line = Polygon::new(vec![p(0.0, 0.0), p(1.0, 1.0)]);
+
+        assert_eq!(None, line.mass_properties(density()));
+    }
+
+    #[test]
+    fn parallel_axis_theorem() {
+        let circle = Circle::new(p(0.0, 0.0), Length::in_meters(1.0));
+        let properties = circle.mass_properties(density());
+
+        let about_edge = properties.moment_of_inertia_about(p(1.0, 0.0));
+
+        assert_approx_eq!(properties.moment_of_inertia.value * 3.0, about_edge.value);
+    }
+
+    #[test]
+    fn combine_two_boxes() {
+        let left = Aabb::new(p(0.0, 0.0), p(1.0, 1.0)).mass_properties(density());
+        let right = Aabb::new(p(1.0, 0.0), p(2.0, 1.0)).mass_properties(density());
+        let whole = Aabb::new(p(0.0, 0.0), p(2.0, 1.0)).mass_properties(density());
+
+        let combined = left.combine(&right);
+
+        assert_eq!(whole.mass, combined.mass);
+        assert_eq!(whole.center_of_mass, combined.center_of_mass);
+        assert_approx_eq!(whole.moment_of_inertia.value, combined.moment_of_inertia.value);
+    }
+
+    #[test]
+    fn solids() {
+        let water = Density::in_kilograms_per_meter_cubed(1000.0);
+
+        let sphere = Sphere { radius: Length::in_meters(1.0) };
+        assert_approx_eq!(4.0 / 3.0 * PI, sphere.volume().value);
+        assert_approx_eq!(4000.0 / 3.0 * PI, sphere.mass(water).value);
+
+        let cuboid = Cuboid { width: Length::in_meters(1.0), height: Length::in_meters(2.0), depth: Length::in_meters(3.0) };
+        assert_eq!(Volume::in_meters_cubed(6.0), cuboid.volume());
+        assert_eq!(Mass::in_kilograms(6000.0), cuboid.mass(water));
+
+        let cylinder = Cylinder { radius: Length::in_meters(2.0), height: Length::in_meters(3.0) };
+        assert_approx_eq!(12.0 * PI, cylinder.volume().value);
+    }
+}
@@ -0,0 +1,490 @@
+use std::ops::*;
+use crate::*;
+use crate::vectors::Vector3;
+
+/// A 2x2 matrix whose entries all share the unit `T`, stored row by row.
+///
+/// Multiplying a `Matrix2<T>` by a `Vector<A>` yields a `Vector<B>` wherever
+/// `Scalar<T> * Scalar<A> = Scalar<B>`.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Matrix2<T> {
+    pub xx: Scalar<T>,
+    pub xy: Scalar<T>,
+    pub yx: Scalar<T>,
+    pub yy: Scalar<T>,
+}
+
+impl<T: Unit> Matrix2<T> {
+    #[inline]
+    pub fn new<U: Into<Scalar<T>>>(xx: U, xy: U, yx: U, yy: U) -> Self {
+        Matrix2 { xx: xx.into(), xy: xy.into(), yx: yx.into(), yy: yy.into() }
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Matrix2 { xx: Scalar::zero(), xy: Scalar::zero(), yx: Scalar::zero(), yy: Scalar::zero() }
+    }
+
+    #[inline]
+    pub fn diagonal(xx: Scalar<T>, yy: Scalar<T>) -> Self {
+        Self::new(xx, Scalar::zero(), Scalar::zero(), yy)
+    }
+
+    #[inline]
+    pub fn from_rows<F: Frame>(x: Vector<T, F>, y: Vector<T, F>) -> Self {
+        Self::new(x.x, x.y, y.x, y.y)
+    }
+
+    #[inline]
+    pub fn from_columns<F: Frame>(x: Vector<T, F>, y: Vector<T, F>) -> Self {
+        Self::new(x.x, y.x, x.y, y.y)
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self::new(self.xx, self.yx, self.xy, self.yy)
+    }
+
+    #[inline]
+    pub fn trace(&self) -> Scalar<T> {
+        self.xx + self.yy
+    }
+
+    #[inline]
+    pub fn determinant<D>(&self) -> Scalar<D>
+        where Scalar<T>: Mul<Output=Scalar<D>>
+    {
+        self.xx * self.yy - self.xy * self.yx
+    }
+}
+
+impl<T: Reciprocal> Matrix2<T> {
+    /// Returns `None` if the matrix is singular.
+    #[inline]
+    pub fn inverse(&self) -> Option<Matrix2<T::Output>> {
+        let det = self.xx.value * self.yy.value - self.xy.value * self.yx.value;
+
+        if det == 0.0 {
+            return None;
+        }
+
+        Some(Matrix2::new(
+            self.yy.value / det,
+            -self.xy.value / det,
+            -self.yx.value / det,
+            self.xx.value / det,
+        ))
+    }
+}
+
+impl Matrix2<Float> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0)
+    }
+
+    /// The matrix equivalent of `Vector::rotate_cw`.
+    #[inline]
+    pub fn rotation(angle: Angle) -> Self {
+        let cos = angle.cos();
+        let sin = angle.sin();
+        Self::new(cos, -sin, sin, cos)
+    }
+}
+
+impl<T: Unit> Neg for Matrix2<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+impl<T: Unit> Add for Matrix2<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.xx + rhs.xx, self.xy + rhs.xy, self.yx + rhs.yx, self.yy + rhs.yy)
+    }
+}
+
+impl<T: Unit> Sub for Matrix2<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.xx - rhs.xx, self.xy - rhs.xy, self.yx - rhs.yx, self.yy - rhs.yy)
+    }
+}
+
+impl<T: Unit> Mul<Float> for Matrix2<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Float) -> Self {
+        Self::new(self.xx * rhs, self.xy * rhs, self.yx * rhs, self.yy * rhs)
+    }
+}
+
+impl<T: Unit> Mul<Matrix2<T>> for Float {
+    type Output = Matrix2<T>;
+    #[inline]
+    fn mul(self, rhs: Matrix2<T>) -> Matrix2<T> {
+        rhs * self
+    }
+}
+
+impl<T: Unit> Div<Float> for Matrix2<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Float) -> Self {
+        Self::new(self.xx / rhs, self.xy / rhs, self.yx / rhs, self.yy / rhs)
+    }
+}
+
+impl<T: Unit, A: Unit, B: Unit, F: Frame> Mul<Vector<A, F>> for Matrix2<T>
+    where Scalar<T>: Mul<Scalar<A>, Output=Scalar<B>>
+{
+    type Output = Vector<B, F>;
+    #[inline]
+    fn mul(self, rhs: Vector<A, F>) -> Vector<B, F> {
+        Vector::from((
+            self.xx * rhs.x + self.xy * rhs.y,
+            self.yx * rhs.x + self.yy * rhs.y,
+        ))
+    }
+}
+
+impl<T: Unit, A: Unit, B: Unit> Mul<Matrix2<A>> for Matrix2<T>
+    where Scalar<T>: Mul<Scalar<A>, Output=Scalar<B>>
+{
+    type Output = Matrix2<B>;
+    #[inline]
+    fn mul(self, rhs: Matrix2<A>) -> Matrix2<B> {
+        Matrix2::new(
+            self.xx * rhs.xx + self.xy * rhs.yx,
+            self.xx * rhs.xy + self.xy * rhs.yy,
+            self.yx * rhs.xx + self.yy * rhs.yx,
+            self.yx * rhs.xy + self.yy * rhs.yy,
+        )
+    }
+}
+
+/// A 3x3 matrix whose entries all share the unit `T`, stored row by row.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Matrix3<T> {
+    pub xx: Scalar<T>,
+    pub xy: Scalar<T>,
+    pub xz: Scalar<T>,
+    pub yx: Scalar<T>,
+    pub yy: Scalar<T>,
+    pub yz: Scalar<T>,
+    pub zx: Scalar<T>,
+    pub zy: Scalar<T>,
+    pub zz: Scalar<T>,
+}
+
+impl<T: Unit> Matrix3<T> {
+    #[inline]
+    pub fn from_rows(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Matrix3 {
+            xx: x.x, xy: x.y, xz: x.z,
+            yx: y.x, yy: y.y, yz: y.z,
+            zx: z.x, zy: z.y, zz: z.z,
+        }
+    }
+
+    #[inline]
+    pub fn from_columns(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Self {
+        Self::from_rows(x, y, z).transpose()
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Matrix3 {
+            xx: Scalar::zero(), xy: Scalar::zero(), xz: Scalar::zero(),
+            yx: Scalar::zero(), yy: Scalar::zero(), yz: Scalar::zero(),
+            zx: Scalar::zero(), zy: Scalar::zero(), zz: Scalar::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn diagonal(xx: Scalar<T>, yy: Scalar<T>, zz: Scalar<T>) -> Self {
+        Matrix3 { xx, yy, zz, ..Self::zero() }
+    }
+
+    #[inline]
+    pub fn row_x(&self) -> Vector3<T> {
+        Vector3::from((self.xx, self.xy, self.xz))
+    }
+
+    #[inline]
+    pub fn row_y(&self) -> Vector3<T> {
+        Vector3::from((self.yx, self.yy, self.yz))
+    }
+
+    #[inline]
+    pub fn row_z(&self) -> Vector3<T> {
+        Vector3::from((self.zx, self.zy, self.zz))
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Matrix3 {
+            xx: self.xx, xy: self.yx, xz: self.zx,
+            yx: self.xy, yy: self.yy, yz: self.zy,
+            zx: self.xz, zy: self.yz, zz: self.zz,
+        }
+    }
+
+    #[inline]
+    pub fn trace(&self) -> Scalar<T> {
+        self.xx + self.yy + self.zz
+    }
+
+    #[inline]
+    pub fn determinant<D2, D3>(&self) -> Scalar<D3>
+        where
+            Scalar<T>: Mul<Output=Scalar<D2>>,
+            Scalar<D2>: Mul<Scalar<T>, Output=Scalar<D3>>,
+    {
+        (self.yy * self.zz - self.yz * self.zy) * self.xx
+            - (self.yx * self.zz - self.yz * self.zx) * self.xy
+            + (self.yx * self.zy - self.yy * self.zx) * self.xz
+    }
+
+    #[inline]
+    fn map(&self, f: impl Fn(Scalar<T>) -> Scalar<T>) -> Self {
+        Matrix3 {
+            xx: f(self.xx), xy: f(self.xy), xz: f(self.xz),
+            yx: f(self.yx), yy: f(self.yy), yz: f(self.yz),
+            zx: f(self.zx), zy: f(self.zy), zz: f(self.zz),
+        }
+    }
+}
+
+impl<T: Reciprocal> Matrix3<T> {
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix3<T::Output>> {
+        let [a, b, c] = [self.xx.value, self.xy.value, self.xz.value];
+        let [d, e, f] = [self.yx.value, self.yy.value, self.yz.value];
+        let [g, h, i] = [self.zx.value, self.zy.value, self.zz.value];
+
+        let cofactor_x = e * i - f * h;
+        let cofactor_y = f * g - d * i;
+        let cofactor_z = d * h - e * g;
+
+        let det = a * cofactor_x + b * cofactor_y + c * cofactor_z;
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let row = |x: Float, y: Float, z: Float| Vector3::from((x / det, y / det, z / det));
+
+        Some(Matrix3::from_rows(
+            row(cofactor_x, c * h - b * i, b * f - c * e),
+            row(cofactor_y, a * i - c * g, c * d - a * f),
+            row(cofactor_z, b * g - a * h, a * e - b * d),
+        ))
+    }
+}
+
+impl Matrix3<Float> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self::diagonal(1.0.into(), 1.0.into(), 1.0.into())
+    }
+}
+
+impl<T: Unit> Neg for Matrix3<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        self * -1.0
+    }
+}
+
+impl<T: Unit> Add for Matrix3<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_rows(
+            self.row_x() + rhs.row_x(),
+            self.row_y() + rhs.row_y(),
+            self.row_z() + rhs.row_z(),
+        )
+    }
+}
+
+impl<T: Unit> Sub for Matrix3<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<T: Unit> Mul<Float> for Matrix3<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Float) -> Self {
+        self.map(|s| s * rhs)
+    }
+}
+
+impl<T: Unit> Mul<Matrix3<T>> for Float {
+    type Output = Matrix3<T>;
+    #[inline]
+    fn mul(self, rhs: Matrix3<T>) -> Matrix3<T> {
+        rhs * self
+    }
+}
+
+impl<T: Unit> Div<Float> for Matrix3<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Float) -> Self {
+        self.map(|s| s / rhs)
+    }
+}
+
+impl<T: Unit, A: Unit, B: Unit> Mul<Vector3<A>> for Matrix3<T>
+    where Scalar<T>: Mul<Scalar<A>, Output=Scalar<B>>
+{
+    type Output = Vector3<B>;
+    #[inline]
+    fn mul(self, rhs: Vector3<A>) -> Vector3<B> {
+        Vector3::from((
+            self.xx * rhs.x + self.xy * rhs.y + self.xz * rhs.z,
+            self.yx * rhs.x + self.yy * rhs.y + self.yz * rhs.z,
+            self.zx * rhs.x + self.zy * rhs.y + self.zz * rhs.z,
+        ))
+    }
+}
+
+impl<T: Unit, A: Unit, B: Unit> Mul<Matrix3<A>> for Matrix3<T>
+    where Scalar<T>: Mul<Scalar<A>, Output=Scalar<B>>
+{
+    type Output = Matrix3<B>;
+    #[inline]
+    fn mul(self, rhs: Matrix3<A>) -> Matrix3<B> {
+        let columns = rhs.transpose();
+        let row = |r: Vector3<T>| Vector3::from((
+            r.x * columns.xx + r.y * columns.xy + r.z * columns.xz,
+            r.x * columns.yx + r.y * columns.yy + r.z * columns.yz,
+            r.x * columns.zx + r.y * columns.zy + r.z * columns.zz,
+        ));
+
+        Matrix3::from_rows(row(self.row_x()), row(self.row_y()), row(self.row_z()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix2_vector_product_converts_units() {
+        let m = Matrix2::<Seconds>::new(2.0, 0.0, 1.0, 3.0);
+        let v = Velocity::in_meters_per_second(1.0, 2.0);
+
+        let p: Position = m * v;
+
+        assert_eq!(Position::in_meters(2.0, 7.0), p);
+    }
+
+    #[test]
+    fn matrix2_dimensionless_product_preserves_units() {
+        let m = Matrix2::identity() * 2.0;
+
+        assert_eq!(Position::in_meters(2.0, 4.0), m * Position::in_meters(1.0, 2.0));
+    }
+
+    #[test]
+    fn matrix2_rotation_matches_vector_rotation() {
+        let angle = Angle::in_degrees(30.0);
+        let p = Position::in_meters(1.0, 2.0);
+
+        assert_eq!(p.rotate_cw(angle), Matrix2::rotation(angle) * p);
+    }
+
+    #[test]
+    fn matrix2_determinant_has_squared_units() {
+        let m = Matrix2::<Meters>::new(3.0, 1.0, 2.0, 4.0);
+
+        assert_eq!(Area::in_meters_squared(10.0), m.determinant());
+    }
+
+    #[test]
+    fn matrix2_transpose() {
+        let m = Matrix2::<Meters>::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(Matrix2::new(1.0, 3.0, 2.0, 4.0), m.transpose());
+    }
+
+    #[test]
+    fn matrix2_inverse_has_reciprocal_units() {
+        let m = Matrix2::<Meters>::new(4.0, 7.0, 2.0, 6.0);
+
+        let inverse: Matrix2<PerMeter> = m.inverse().unwrap();
+        let product: Matrix2<Float> = m * inverse;
+
+        assert_approx_eq!(1.0, product.xx.value);
+        assert_approx_eq!(0.0, product.xy.value);
+        assert_approx_eq!(0.0, product.yx.value);
+        assert_approx_eq!(1.0, product.yy.value);
+    }
+
+    #[test]
+    fn matrix2_singular_inverse_is_none() {
+        let m = Matrix2::<Float>::new(1.0, 2.0, 2.0, 4.0);
+
+        assert_eq!(None, m.inverse());
+    }
+
+    fn sample3() -> Matrix3<Meters> {
+        Matrix3::from_rows(
+            Meters::get_vector3(2.0, 0.0, 1.0),
+            Meters::get_vector3(1.0, 3.0, 2.0),
+            Meters::get_vector3(1.0, 1.0, 2.0),
+        )
+    }
+
+    #[test]
+    fn matrix3_determinant_has_cubed_units() {
+        assert_eq!(Volume::in_meters_cubed(6.0), sample3().determinant());
+    }
+
+    #[test]
+    fn matrix3_inverse_has_reciprocal_units() {
+        let m = sample3();
+        let inverse: Matrix3<PerMeter> = m.inverse().unwrap();
+        let product: Matrix3<Float> = m * inverse;
+        let identity = Matrix3::<Float>::identity();
+
+        for (a, b) in [
+            (product.row_x(), identity.row_x()),
+            (product.row_y(), identity.row_y()),
+            (product.row_z(), identity.row_z()),
+        ].iter() {
+            assert_approx_eq!(b.x.value, a.x.value);
+            assert_approx_eq!(b.y.value, a.y.value);
+            assert_approx_eq!(b.z.value, a.z.value);
+        }
+    }
+
+    #[test]
+    fn matrix3_vector_product_converts_units() {
+        let m = Matrix3::<Seconds>::diagonal(1.0.into(), 2.0.into(), 3.0.into());
+        let v = MetersPerSecond::get_vector3(1.0, 1.0, 1.0);
+
+        assert_eq!(Meters::get_vector3(1.0, 2.0, 3.0), m * v);
+    }
+
+    #[test]
+    fn matrix3_transpose() {
+        let m = sample3();
+
+        assert_eq!(m, m.transpose().transpose());
+        assert_eq!(m, Matrix3::from_columns(m.row_x(), m.row_y(), m.row_z()).transpose());
+    }
+}
@@ -0,0 +1,436 @@
+use crate::*;
+
+/// The acceleration that a mass with gravitational parameter `mu` at `offset` causes, softened by
+/// `softening` so that close encounters stay finite.
+#[inline]
+fn attraction(offset: Position, mu: GravitationalParameter, softening: Length) -> Acceleration {
+    let distance_squared = offset.dot(offset) + softening * softening;
+
+    if distance_squared == Area::zero() {
+        return Acceleration::zero();
+    }
+
+    offset / distance_squared.sqrt() * (mu / distance_squared)
+}
+
+/// The acceleration of every body from the pull of all the others, by summing every pair.
+///
+/// # Panics
+/// If the slices have different lengths.
+pub fn direct_accelerations(positions: &[Position], masses: &[Mass], softening: Length) -> Vec<Acceleration> {
+    assert_eq!(positions.len(), masses.len(), "every body needs a position and a mass");
+
+    positions.iter()
+        .enumerate()
+        .map(|(i, &position)| {
+            positions.iter()
+                .zip(masses)
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Acceleration::zero(), |sum, (_, (&other, &mass))| {
+                    sum + attraction(other - position, GRAVITATIONAL_CONSTANT * mass, softening)
+                })
+        })
+        .collect()
+}
+
+/// The acceleration of every body using a Barnes–Hut tree.
+///
+/// Groups of bodies that appear smaller than `theta` radians from a body are treated as a single
+/// mass at their center of mass. A `theta` of zero gives the direct sum, while around 0.5 is
+/// typical, costing O(n log n) rather than O(n²).
+///
+/// # Panics
+/// If the slices have different lengths.
+pub fn barnes_hut_accelerations(positions: &[Position], masses: &[Mass], softening: Length, theta: Float) -> Vec<Acceleration> {
+    assert_eq!(positions.len(), masses.len(), "every body needs a position and a mass");
+
+    let bounds = match Aabb::from_points(positions.iter().copied()) {
+        Some(bounds) => bounds,
+        None => return vec![],
+    };
+
+    let half_size = bounds.width().max(bounds.height()) / 2.0;
+    let mut tree = MassTree { positions, masses, cells: vec![] };
+    tree.build((0..positions.len()).collect(), bounds.center(), 0, half_size, 0);
+
+    positions.iter()
+        .enumerate()
+        .map(|(i, &position)| tree.acceleration(0, i, position, true, softening, theta))
+        .collect()
+}
+
+/// The depth at which cells stop splitting, so that coincident bodies share a cell.
+const MAX_TREE_DEPTH: usize = 48;
+
+struct MassTree<'a> {
+    positions: &'a [Position],
+    masses: &'a [Mass],
+    cells: Vec<MassCell>,
+}
+
+struct MassCell {
+    center: Position,
+    quadrant: usize,
+    size: Length,
+    mass: Mass,
+    center_of_mass: Position,
+    bodies: Vec<usize>,
+    children: Vec<usize>,
+}
+
+impl MassTree<'_> {
+    /// Adds a cell holding `bodies` and its descendants, returning its index.
+    fn build(&mut self, bodies: Vec<usize>, center: Position, quadrant: usize, half_size: Length, depth: usize) -> usize {
+        let mut mass = Mass::zero();
+        let mut center_of_mass = Position::zero();
+
+        for &i in &bodies {
+            mass += self.masses[i];
+            if mass > Mass::zero() {
+                center_of_mass = center_of_mass + (self.positions[i] - center_of_mass) * (self.masses[i] / mass);
+            }
+        }
+
+        let index = self.cells.len();
+        self.cells.push(MassCell { center, quadrant, size: half_size * 2.0, mass, center_of_mass, bodies: vec![], children: vec![] });
+
+        if bodies.len() <= 1 || depth >= MAX_TREE_DEPTH {
+            self.cells[index].bodies = bodies;
+            return index;
+        }
+
+        let mut quadrants = [vec![], vec![], vec![], vec![]];
+        for i in bodies {
+            quadrants[quadrant_of(self.positions[i], center)].push(i);
+        }
+
+        let quarter = half_size / 2.0;
+
+        for (quadrant, bodies) in quadrants.iter_mut().enumerate() {
+            if bodies.is_empty() {
+                continue;
+            }
+
+            let dx = if quadrant % 2 == 1 { quarter } else { -quarter };
+            let dy = if quadrant >= 2 { quarter } else { -quarter };
+            let child_center = center + Position::from((dx, dy));
+
+            let child = self.build(std::mem::take(bodies), child_center, quadrant, quarter, depth + 1);
+            self.cells[index].children.push(child);
+        }
+
+        index
+    }
+
+    /// The acceleration on `body` from the bodies in `cell`, where `holds_body` is whether the cell contains it.
+    fn acceleration(&self, cell: usize, body: usize, position: Position, holds_body: bool, softening: Length, theta: Float) -> Acceleration {
+        let cell = &self.cells[cell];
+
+        if cell.children.is_empty() {
+            return cell.bodies.iter()
+                .filter(|&&other| other != body)
+                .fold(Acceleration::zero(), |sum, &other| {
+                    let mu = GRAVITATIONAL_CONSTANT * self.masses[other];
+                    sum + attraction(self.positions[other] - position, mu, softening)
+                });
+        }
+
+        // a cell containing the body is always opened, so that the body never attracts itself
+        let offset = cell.center_of_mass - position;
+
+        if !holds_body && cell.size < offset.magnitude() * theta {
+            return attraction(offset, GRAVITATIONAL_CONSTANT * cell.mass, softening);
+        }
+
+        let quadrant = quadrant_of(position, cell.center);
+
+        cell.children.iter()
+            .fold(Acceleration::zero(), |sum, &child| {
+                let holds_body = holds_body && self.cells[child].quadrant == quadrant;
+                sum + self.acceleration(child, body, position, holds_body, softening, theta)
+            })
+    }
+}
+
+/// The index of the quadrant around `center` that `position` falls in, counting east then north.
+#[inline]
+fn quadrant_of(position: Position, center: Position) -> usize {
+    let east = (position.x >= center.x) as usize;
+    let north = (position.y >= center.y) as usize;
+    east + 2 * north
+}
+
+/// How accelerations are summed.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GravitySum {
+    Direct,
+    BarnesHut { theta: Float },
+}
+
+/// A symplectic scheme built from alternating drifts and kicks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Symplectic {
+    /// Second order drift-kick-drift, one force evaluation per step.
+    Leapfrog,
+    /// Yoshida's fourth order composition of three leapfrog steps, three force evaluations per step.
+    Yoshida4,
+}
+
+impl Symplectic {
+    /// The fractions of the step to drift and to kick, alternating and starting with a drift.
+    fn coefficients(&self) -> (Vec<Float>, Vec<Float>) {
+        match self {
+            Symplectic::Leapfrog => (vec![0.5, 0.5], vec![1.0]),
+            Symplectic::Yoshida4 => {
+                let cube_root = 2.0_f64.cbrt();
+                let w1 = 1.0 / (2.0 - cube_root);
+                let w0 = -cube_root * w1;
+
+                (
+                    vec![w1 / 2.0, (w0 + w1) / 2.0, (w0 + w1) / 2.0, w1 / 2.0],
+                    vec![w1, w0, w1],
+                )
+            }
+        }
+    }
+}
+
+/// A system of bodies attracting each other by gravity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NBody {
+    pub masses: Vec<Mass>,
+    pub positions: Vec<Position>,
+    pub velocities: Vec<Velocity>,
+    pub softening: Length,
+    pub sum: GravitySum,
+}
+
+impl NBody {
+    #[inline]
+    pub fn new(softening: Length, sum: GravitySum) -> Self {
+        NBody { masses: vec![], positions: vec![], velocities: vec![], softening, sum }
+    }
+
+    /// Adds a body, returning its index.
+    pub fn add(&mut self, mass: Mass, position: Position, velocity: Velocity) -> usize {
+        self.masses.push(mass);
+        self.positions.push(position);
+        self.velocities.push(velocity);
+        self.masses.len() - 1
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.masses.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.masses.is_empty()
+    }
+
+    pub fn accelerations(&self) -> Vec<Acceleration> {
+        match self.sum {
+            GravitySum::Direct => direct_accelerations(&self.positions, &self.masses, self.softening),
+            GravitySum::BarnesHut { theta } => barnes_hut_accelerations(&self.positions, &self.masses, self.softening, theta),
+        }
+    }
+
+    /// Advances every body by `dt`.
+    pub fn step(&mut self, dt: Time, scheme: Symplectic) {
+        let (drifts, kicks) = scheme.coefficients();
+
+        for (i, &drift) in drifts.iter().enumerate() {
+            self.drift(dt * drift);
+
+            if let Some(&kick) = kicks.get(i) {
+                let accelerations = self.accelerations();
+                for (velocity, acceleration) in self.velocities.iter_mut().zip(accelerations) {
+                    *velocity += acceleration * (dt * kick);
+                }
+            }
+        }
+    }
+
+    fn drift(&mut self, dt: Time) {
+        for (position, &velocity) in self.positions.iter_mut().zip(&self.velocities) {
+            *position += velocity * dt;
+        }
+    }
+
+    pub fn kinetic_energy(&self) -> Energy {
+        self.masses.iter()
+            .zip(&self.velocities)
+            .fold(Energy::zero(), |sum, (&mass, &velocity)| sum + mass * velocity.dot(velocity) / 2.0)
+    }
+
+    /// The softened gravitational potential energy of every pair.
+    pub fn potential_energy(&self) -> Energy {
+        let mut energy = Energy::zero();
+
+        for i in 0..self.len() {
+            for j in i + 1..self.len() {
+                let offset = self.positions[j] - self.positions[i];
+                let distance = (offset.dot(offset) + self.softening * self.softening).sqrt();
+                energy -= self.masses[i] * (GRAVITATIONAL_CONSTANT * self.masses[j] / distance);
+            }
+        }
+
+        energy
+    }
+
+    #[inline]
+    pub fn total_energy(&self) -> Energy {
+        self.kinetic_energy() + self.potential_energy()
+    }
+
+    pub fn momentum(&self) -> Momentum {
+        self.masses.iter()
+            .zip(&self.velocities)
+            .fold(Momentum::zero(), |sum, (&mass, &velocity)| sum + mass * velocity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{random, scattered};
+
+    const AU: Float = 1.495_978_707e11;
+    const SUN: Float = 1.988_47e30;
+    const EARTH: Float = 5.972_2e24;
+
+    /// Scattered but repeatable bodies in a square a million kilometers across.
+    fn bodies(count: usize) -> (Vec<Position>, Vec<Mass>) {
+        let mut next = random(987_654_321);
+        let masses = (0..count).map(|_| Mass::in_kilograms(1e22 * (1.0 + next()))).collect();
+
+        (scattered(count, 1e9), masses)
+    }
+
+    fn sun_and_earth(sum: GravitySum) -> NBody {
+        let mut system = NBody::new(Length::zero(), sum);
+        let mu = GRAVITATIONAL_CONSTANT * Mass::in_kilograms(SUN + EARTH);
+        let speed = (mu / Length::in_meters(AU)).sqrt();
+
+        // start at rest overall, so the system does not wander off
+        let earth_share = EARTH / (SUN + EARTH);
+        system.add(Mass::in_kilograms(SUN), Position::in_meters(-AU * earth_share, 0.0), Velocity::from((Speed::zero(), -speed * earth_share)));
+        system.add(Mass::in_kilograms(EARTH), Position::in_meters(AU * (1.0 - earth_share), 0.0), Velocity::from((Speed::zero(), speed * (1.0 - earth_share))));
+        system
+    }
+
+    #[test]
+    fn two_body_attraction() {
+        let positions = [Position::zero(), Position::in_meters(AU, 0.0)];
+        let masses = [Mass::in_kilograms(SUN), Mass::in_kilograms(EARTH)];
+
+        let accelerations = direct_accelerations(&positions, &masses, Length::zero());
+
+        assert_approx_eq!(6.674_30e-11 * EARTH / (AU * AU), accelerations[0].x.value);
+        assert_approx_eq!(-6.674_30e-11 * SUN / (AU * AU), accelerations[1].x.value);
+        assert_eq!(0.0, accelerations[1].y.value);
+    }
+
+    #[test]
+    fn softening_keeps_coincident_bodies_finite() {
+        let positions = [Position::zero(), Position::zero()];
+        let masses = [Mass::in_kilograms(1.0), Mass::in_kilograms(1.0)];
+
+        let accelerations = direct_accelerations(&positions, &masses, Length::in_meters(1.0));
+
+        assert_eq!(Acceleration::zero(), accelerations[0]);
+    }
+
+    #[test]
+    fn forces_balance() {
+        let (positions, masses) = bodies(50);
+        let accelerations = direct_accelerations(&positions, &masses, Length::zero());
+
+        let net = masses.iter()
+            .zip(&accelerations)
+            .fold(Acceleration::zero(), |sum, (&mass, &acceleration)| sum + acceleration * (mass / masses[0]));
+
+        assert!(net.magnitude().value < 1e-12 * accelerations[0].magnitude().value * 50.0);
+    }
+
+    #[test]
+    fn barnes_hut_approximates_direct_sum() {
+        let (positions, masses) = bodies(300);
+        let softening = Length::in_meters(1e6);
+
+        let direct = direct_accelerations(&positions, &masses, softening);
+        let exact = barnes_hut_accelerations(&positions, &masses, softening, 0.0);
+        let approximate = barnes_hut_accelerations(&positions, &masses, softening, 0.5);
+
+        let mut error = 0.0;
+        let mut total = 0.0;
+
+        for i in 0..positions.len() {
+            let scale = direct[i].magnitude().value;
+            assert!((exact[i] - direct[i]).magnitude().value < 1e-9 * scale);
+
+            error += (approximate[i] - direct[i]).magnitude_squared();
+            total += direct[i].magnitude_squared();
+        }
+
+        let relative = (error / total).sqrt();
+        assert!(relative < 0.01, "relative error {}", relative);
+    }
+
+    #[test]
+    fn barnes_hut_never_approximates_a_cell_holding_the_body() {
+        // the light body shares a quadrant with a heavy one whose pull moves the center of mass
+        // more than a cell width away, so with theta of one the cell could be mistaken as distant
+        let positions = [Position::zero(), Position::in_meters(49.0, 49.0), Position::in_meters(100.0, 100.0)];
+        let masses = [Mass::in_kilograms(3e9), Mass::in_kilograms(1e10), Mass::in_kilograms(1e10)];
+
+        let mut direct = NBody::new(Length::zero(), GravitySum::Direct);
+        let mut approximate = NBody::new(Length::zero(), GravitySum::BarnesHut { theta: 1.0 });
+        for (&position, &mass) in positions.iter().zip(masses.iter()) {
+            direct.add(mass, position, Velocity::zero());
+            approximate.add(mass, position, Velocity::zero());
+        }
+
+        let direct = direct.accelerations();
+        let approximate = approximate.accelerations();
+
+        for i in 0..2 {
+            assert!((approximate[i] - direct[i]).magnitude().value < 1e-9 * direct[i].magnitude().value);
+        }
+    }
+
+    #[test]
+    fn earth_orbit_is_stable_for_a_thousand_years() {
+        let dt = Time::in_days(2.0);
+        let steps = (Time::in_years(1000.0) / dt).round() as usize;
+
+        for scheme in [Symplectic::Leapfrog, Symplectic::Yoshida4].iter() {
+            let mut system = sun_and_earth(GravitySum::Direct);
+            let energy = system.total_energy();
+
+            for _ in 0..steps {
+                system.step(dt, *scheme);
+            }
+
+            let drift = (system.total_energy() - energy) / energy;
+            let separation = (system.positions[1] - system.positions[0]).magnitude();
+
+            assert!(drift.abs() < 1e-4, "{:?} energy drift {}", scheme, drift);
+            assert_approx_eq!(AU, separation.value, 1e-3);
+            assert!(system.momentum().magnitude().value < 1e-6 * EARTH);
+        }
+    }
+
+    #[test]
+    fn barnes_hut_stepper() {
+        let mut system = sun_and_earth(GravitySum::BarnesHut { theta: 0.5 });
+        let direct = sun_and_earth(GravitySum::Direct);
+
+        assert_eq!(direct.accelerations(), system.accelerations());
+
+        system.step(Time::in_days(1.0), Symplectic::Yoshida4);
+        assert_eq!(2, system.len());
+    }
+}
@@ -0,0 +1,350 @@
+use std::ops::*;
+use crate::*;
+
+/// A quantity that the adaptive solver can integrate.
+///
+/// The solver combines states linearly, and compares the estimated error of each step against a
+/// tolerance given as a state of the same type.
+pub trait OdeState: Copy + Add<Output=Self> + Sub<Output=Self> + Mul<Float, Output=Self> {
+    /// The largest ratio of a component of `self` to the matching component of `tolerance`.
+    fn error_ratio(&self, tolerance: &Self) -> Float;
+}
+
+impl<T: Unit> OdeState for Scalar<T> {
+    #[inline]
+    fn error_ratio(&self, tolerance: &Self) -> Float {
+        (self.value / tolerance.value).abs()
+    }
+}
+
+impl<T: Unit, F: Frame> OdeState for Vector<T, F> {
+    #[inline]
+    fn error_ratio(&self, tolerance: &Self) -> Float {
+        self.x.error_ratio(&tolerance.x)
+            .max(self.y.error_ratio(&tolerance.y))
+    }
+}
+
+impl<T: Unit> OdeState for Vector3<T> {
+    #[inline]
+    fn error_ratio(&self, tolerance: &Self) -> Float {
+        self.x.error_ratio(&tolerance.x)
+            .max(self.y.error_ratio(&tolerance.y))
+            .max(self.z.error_ratio(&tolerance.z))
+    }
+}
+
+/// The Dormand–Prince 5(4) embedded Runge–Kutta method with adaptive steps.
+///
+/// The rate of change `R` of a state `S` must satisfy `R * Time = S`, so a heat flow in J/s
+/// integrates to joules and a mass flow in kg/s integrates to kilograms.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DormandPrince<S> {
+    /// The largest error allowed per step, in the units of the state.
+    pub tolerance: S,
+    pub initial_step: Time,
+    pub min_step: Time,
+    pub max_step: Time,
+}
+
+/// The result of an adaptive solve, with dense output over the whole interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<S> {
+    pub start: Time,
+    pub initial: S,
+    steps: Vec<DenseStep<S>>,
+    pub accepted_steps: usize,
+    pub rejected_steps: usize,
+    pub evaluations: usize,
+}
+
+/// The interpolating polynomial over one accepted step.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct DenseStep<S> {
+    start: Time,
+    length: Time,
+    coefficients: [S; 5],
+}
+
+// Butcher tableau
+const C: [Float; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+const A2: [Float; 1] = [1.0 / 5.0];
+const A3: [Float; 2] = [3.0 / 40.0, 9.0 / 40.0];
+const A4: [Float; 3] = [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0];
+const A5: [Float; 4] = [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0];
+const A6: [Float; 5] = [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0];
+const A7: [Float; 6] = [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0];
+
+/// The difference between the fifth and fourth order weights.
+const E: [Float; 7] = [
+    71.0 / 57600.0, 0.0, -71.0 / 16695.0, 71.0 / 1920.0, -17253.0 / 339200.0, 22.0 / 525.0, -1.0 / 40.0,
+];
+
+/// Dense output weights from Hairer, Nørsett and Wanner.
+const D: [Float; 7] = [
+    -12715105075.0 / 11282082432.0, 0.0, 87487479700.0 / 32700410799.0, -10690763975.0 / 1880347072.0,
+    701980252875.0 / 199316789632.0, -1453857185.0 / 822651844.0, 69997945.0 / 29380423.0,
+];
+
+impl<S: OdeState> DormandPrince<S> {
+    /// A solver with steps between a millionth of a second and an hour, starting at a second.
+    #[inline]
+    pub fn new(tolerance: S) -> Self {
+        DormandPrince {
+            tolerance,
+            initial_step: Time::in_seconds(1.0),
+            min_step: Time::in_seconds(1e-6),
+            max_step: Time::in_hours(1.0),
+        }
+    }
+
+    /// Integrates `derivative` from `start` to `end`, beginning at `initial`.
+    ///
+    /// Returns `None` if `end` is before `start`, since only forward integration is supported,
+    /// or if the step needed to meet the tolerance falls below the minimum step, such as when
+    /// the solution blows up.
+    pub fn solve<R, G>(&self, derivative: G, start: Time, initial: S, end: Time) -> Option<Solution<S>>
+        where
+            R: Copy + Mul<Time, Output=S>,
+            G: Fn(Time, S) -> R,
+    {
+        if end < start {
+            return None;
+        }
+
+        let mut solution = Solution {
+            start,
+            initial,
+            steps: vec![],
+            accepted_steps: 0,
+            rejected_steps: 0,
+            evaluations: 1,
+        };
+
+        let mut time = start;
+        let mut state = initial;
+        let mut k1 = derivative(time, state);
+        let mut step = self.initial_step.min(self.max_step);
+        let mut rejected_last = false;
+
+        while time < end {
+            step = step.min(end - time);
+
+            let h = |k: R| k * step;
+            let combine = |weights: &[Float], ks: &[S]| {
+                weights.iter().zip(ks).fold(state, |sum, (&w, &k)| sum + k * w)
+            };
+
+            let mut ks = vec![h(k1)];
+            for weights in [&A2[..], &A3[..], &A4[..], &A5[..], &A6[..]].iter() {
+                let stage = ks.len();
+                ks.push(h(derivative(time + step * C[stage], combine(weights, &ks))));
+            }
+
+            let next = combine(&A7, &ks);
+            let k7 = derivative(time + step, next);
+            ks.push(h(k7));
+            solution.evaluations += 6;
+
+            let error = E.iter().zip(&ks).skip(1).fold(ks[0] * E[0], |sum, (&e, &k)| sum + k * e);
+            let ratio = error.error_ratio(&self.tolerance);
+
+            if ratio <= 1.0 {
+                let difference = next - state;
+                let bspl = ks[0] - difference;
+                let dense = D.iter().zip(&ks).skip(1).fold(ks[0] * D[0], |sum, (&d, &k)| sum + k * d);
+
+                solution.steps.push(DenseStep {
+                    start: time,
+                    length: step,
+                    coefficients: [state, difference, bspl, difference - ks[6] - bspl, dense],
+                });
+                solution.accepted_steps += 1;
+
+                time = if end - time <= step { end } else { time + step };
+                state = next;
+                k1 = k7;
+            } else {
+                solution.rejected_steps += 1;
+
+                if step <= self.min_step {
+                    return None;
+                }
+            }
+
+            // grow by at most five times, or not at all straight after a rejection
+            let factor = if ratio == 0.0 { 5.0 } else { 0.9 * ratio.powf(-0.2) };
+            let factor = factor.clamp(0.2, if rejected_last || ratio > 1.0 { 1.0 } else { 5.0 });
+            rejected_last = ratio > 1.0;
+
+            step = (step * factor).max(self.min_step).min(self.max_step);
+        }
+
+        Some(solution)
+    }
+}
+
+impl<S: OdeState> Solution<S> {
+    #[inline]
+    pub fn end(&self) -> Time {
+        self.steps.last().map(|step| step.start + step.length).unwrap_or(self.start)
+    }
+
+    #[inline]
+    pub fn final_state(&self) -> S {
+        self.steps.last().map(|step| step.coefficients[0] + step.coefficients[1]).unwrap_or(self.initial)
+    }
+
+    /// The state at any time within the solved interval, interpolated to fourth order.
+    pub fn sample(&self, time: Time) -> Option<S> {
+        if time < self.start || time > self.end() {
+            return None;
+        }
+
+        let index = self.steps
+            .partition_point(|step| step.start + step.length < time)
+            .min(self.steps.len().checked_sub(1)?);
+        let step = &self.steps[index];
+
+        let theta = (time - step.start) / step.length;
+        let [r1, r2, r3, r4, r5] = step.coefficients;
+
+        Some(r1 + (r2 + (r3 + (r4 + r5 * (1.0 - theta)) * theta) * (1.0 - theta)) * theta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn energy_decay() {
+        // heat leaking out of a body with a time constant of ten seconds
+        let tau = Time::in_seconds(10.0);
+        let leak = |_: Time, energy: Energy| -> EnergyRate { -energy / tau };
+        let solver = DormandPrince::new(Energy::in_joules(1e-6));
+
+        let solution = solver
+            .solve(leak, Time::zero(), Energy::in_joules(100.0), Time::in_seconds(30.0))
+            .unwrap();
+
+        assert_eq!(Time::in_seconds(30.0), solution.end());
+        assert_approx_eq!(100.0 * (-3.0_f64).exp(), solution.final_state().value, 1e-7);
+
+        for &t in &[0.0, 0.37, 4.2, 17.5, 29.99] {
+            let sampled = solution.sample(Time::in_seconds(t)).unwrap();
+            assert_approx_eq!(100.0 * (-t / 10.0).exp(), sampled.value, 1e-6);
+        }
+
+        assert_eq!(None, solution.sample(Time::in_seconds(31.0)));
+        assert_eq!(None, solver.solve(leak, Time::in_seconds(30.0), Energy::in_joules(100.0), Time::zero()));
+    }
+
+    #[test]
+    fn constant_mass_flow_is_exact() {
+        let flow = |_: Time, _: Mass| MassRate::in_kilograms_per_second(-2.5);
+        let solver = DormandPrince::new(Mass::in_kilograms(1e-9));
+
+        let solution = solver.solve(flow, Time::zero(), Mass::in_kilograms(100.0), Time::in_seconds(10.0)).unwrap();
+
+        assert_approx_eq!(75.0, solution.final_state().value);
+        assert_eq!(0, solution.rejected_steps);
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct Oscillator {
+        position: Length,
+        velocity: Speed,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct OscillatorRate {
+        velocity: Speed,
+        acceleration: AccelScalar,
+    }
+
+    impl Add for Oscillator {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Oscillator { position: self.position + rhs.position, velocity: self.velocity + rhs.velocity }
+        }
+    }
+
+    impl Sub for Oscillator {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Oscillator { position: self.position - rhs.position, velocity: self.velocity - rhs.velocity }
+        }
+    }
+
+    impl Mul<Float> for Oscillator {
+        type Output = Self;
+        fn mul(self, rhs: Float) -> Self {
+            Oscillator { position: self.position * rhs, velocity: self.velocity * rhs }
+        }
+    }
+
+    impl Mul<Time> for OscillatorRate {
+        type Output = Oscillator;
+        fn mul(self, rhs: Time) -> Oscillator {
+            Oscillator { position: self.velocity * rhs, velocity: self.acceleration * rhs }
+        }
+    }
+
+    impl OdeState for Oscillator {
+        fn error_ratio(&self, tolerance: &Self) -> Float {
+            self.position.error_ratio(&tolerance.position)
+                .max(self.velocity.error_ratio(&tolerance.velocity))
+        }
+    }
+
+    #[test]
+    fn composite_state_with_rejected_steps() {
+        let tau = Time::in_seconds(1.0);
+        let spring = |_: Time, state: Oscillator| OscillatorRate {
+            velocity: state.velocity,
+            acceleration: -state.position / tau / tau,
+        };
+
+        let mut solver = DormandPrince::new(Oscillator {
+            position: Length::in_meters(1e-8),
+            velocity: Speed::in_meters_per_second(1e-8),
+        });
+        solver.initial_step = Time::in_seconds(100.0);
+
+        let start = Oscillator { position: Length::in_meters(1.0), velocity: Speed::zero() };
+        let solution = solver.solve(spring, Time::zero(), start, Time::in_seconds(20.0)).unwrap();
+
+        assert!(solution.rejected_steps > 0);
+        assert_eq!(1 + 6 * (solution.accepted_steps + solution.rejected_steps), solution.evaluations);
+
+        let end = solution.final_state();
+        assert_approx_eq!(20.0_f64.cos(), end.position.value, 1e-6);
+        assert_approx_eq!(-20.0_f64.sin(), end.velocity.value, 1e-6);
+
+        let middle = solution.sample(Time::in_seconds(7.3)).unwrap();
+        assert_approx_eq!(7.3_f64.cos(), middle.position.value, 1e-6);
+    }
+
+    #[test]
+    fn vector_state() {
+        let gravity = |_: Time, _: Velocity| Acceleration::in_meters_per_second_squared(0.0, -9.8);
+        let solver = DormandPrince::new(Velocity::in_meters_per_second(1e-9, 1e-9));
+
+        let solution = solver
+            .solve(gravity, Time::zero(), Velocity::in_meters_per_second(3.0, 20.0), Time::in_seconds(2.0))
+            .unwrap();
+
+        assert_approx_eq!(3.0, solution.final_state().x.value);
+        assert_approx_eq!(0.4, solution.final_state().y.value);
+    }
+
+    #[test]
+    fn blow_up_fails_to_converge() {
+        // y' = y² / (1 kg·s) reaches infinity at one second
+        let rate = |_: Time, mass: Mass| MassRate::in_kilograms_per_second(mass.value * mass.value);
+        let solver = DormandPrince::new(Mass::in_kilograms(1e-6));
+
+        assert_eq!(None, solver.solve(rate, Time::zero(), Mass::in_kilograms(1.0), Time::in_seconds(2.0)));
+    }
+}
@@ -0,0 +1,369 @@
+use std::f64::consts::PI;
+use crate::*;
+
+/// Eccentricities this close to one are treated as parabolic.
+const PARABOLIC_TOLERANCE: Float = 1e-9;
+
+/// A Keplerian orbit around a central body, in the plane.
+///
+/// Angles are measured counter-clockwise from the x-axis. A prograde orbit, with an inclination
+/// of zero, moves counter-clockwise, and a retrograde orbit, with an inclination of π, moves
+/// clockwise. The shape is stored by periapsis distance so that circular, elliptic, parabolic
+/// and hyperbolic orbits are all represented the same way.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Orbit {
+    pub mu: GravitationalParameter,
+    pub periapsis: Length,
+    pub eccentricity: Float,
+    pub inclination: Angle,
+    pub argument_of_periapsis: Angle,
+    pub time_of_periapsis: Time,
+}
+
+impl Orbit {
+    /// A prograde orbit around `central_mass`.
+    #[inline]
+    pub fn from_periapsis(
+        central_mass: Mass,
+        periapsis: Length,
+        eccentricity: Float,
+        argument_of_periapsis: Angle,
+        time_of_periapsis: Time,
+    ) -> Self {
+        Orbit {
+            mu: GRAVITATIONAL_CONSTANT * central_mass,
+            periapsis,
+            eccentricity,
+            inclination: Angle::zero(),
+            argument_of_periapsis,
+            time_of_periapsis,
+        }
+    }
+
+    /// The orbit of a body at `position` moving at `velocity` at `time`, relative to `central_mass`.
+    ///
+    /// Returns `None` for a body at the center or moving straight toward or away from it.
+    pub fn from_state(central_mass: Mass, position: Position, velocity: Velocity, time: Time) -> Option<Self> {
+        let mu = GRAVITATIONAL_CONSTANT * central_mass;
+        let radius = position.magnitude();
+        let angular_momentum: SpecificAngularMomentum = position.cross(velocity);
+
+        if radius == Length::zero() || angular_momentum == SpecificAngularMomentum::zero() {
+            return None;
+        }
+
+        // the eccentricity vector, ((v² - μ/r) r - (r·v) v) / μ
+        let excess = velocity.dot(velocity) - mu / radius;
+        let radial: SpecificAngularMomentum = position.dot(velocity);
+        let ex = (position.x * excess - radial * velocity.x) / mu;
+        let ey = (position.y * excess - radial * velocity.y) / mu;
+        let eccentricity = ex.hypot(ey);
+
+        let semi_latus_rectum = angular_momentum / (mu / angular_momentum);
+        let direction = angular_momentum.value.signum();
+
+        let argument_of_periapsis = if eccentricity < 1e-12 { 0.0 } else { ey.atan2(ex) };
+        let true_anomaly = normalize(direction * (position.y.value.atan2(position.x.value) - argument_of_periapsis));
+
+        let mut orbit = Orbit {
+            mu,
+            periapsis: semi_latus_rectum / (1.0 + eccentricity),
+            eccentricity,
+            inclination: Angle::in_radians(if direction > 0.0 { 0.0 } else { PI }),
+            argument_of_periapsis: Angle::in_radians(argument_of_periapsis),
+            time_of_periapsis: Time::zero(),
+        };
+
+        orbit.time_of_periapsis = time - orbit.time_since_periapsis(true_anomaly);
+        Some(orbit)
+    }
+
+    #[inline]
+    pub fn is_prograde(&self) -> bool {
+        self.inclination.cos() > 0.0
+    }
+
+    #[inline]
+    pub fn is_parabolic(&self) -> bool {
+        (self.eccentricity - 1.0).abs() < PARABOLIC_TOLERANCE
+    }
+
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.eccentricity < 1.0 && !self.is_parabolic()
+    }
+
+    #[inline]
+    pub fn semi_latus_rectum(&self) -> Length {
+        self.periapsis * (1.0 + self.eccentricity)
+    }
+
+    /// Negative for hyperbolic orbits, and `None` for parabolic ones.
+    #[inline]
+    pub fn semi_major_axis(&self) -> Option<Length> {
+        if self.is_parabolic() {
+            None
+        } else {
+            Some(self.periapsis / (1.0 - self.eccentricity))
+        }
+    }
+
+    /// The farthest distance, or `None` for orbits that escape.
+    #[inline]
+    pub fn apoapsis(&self) -> Option<Length> {
+        if self.is_closed() {
+            Some(self.semi_latus_rectum() / (1.0 - self.eccentricity))
+        } else {
+            None
+        }
+    }
+
+    /// The time for one revolution, or `None` for orbits that escape.
+    #[inline]
+    pub fn period(&self) -> Option<Time> {
+        if self.is_closed() {
+            Some(Scalar::<Float>::from(2.0 * PI) / self.mean_motion())
+        } else {
+            None
+        }
+    }
+
+    /// The kinetic plus potential energy per unit mass, zero for parabolic orbits.
+    #[inline]
+    pub fn specific_energy(&self) -> EnergyDensity {
+        self.mu / self.semi_latus_rectum() * ((self.eccentricity.powi(2) - 1.0) / 2.0)
+    }
+
+    /// The mean motion, `sqrt(μ / |a|³)`, or the parabolic equivalent `sqrt(μ / p³)`.
+    fn mean_motion(&self) -> Scalar<PerSecond> {
+        let length = match self.semi_major_axis() {
+            Some(a) => a.abs(),
+            None => self.semi_latus_rectum(),
+        };
+
+        (self.mu / length).sqrt() / length
+    }
+
+    /// The time taken to travel from periapsis to `true_anomaly`, negative before periapsis.
+    fn time_since_periapsis(&self, true_anomaly: Float) -> Time {
+        let e = self.eccentricity;
+        let half = true_anomaly / 2.0;
+
+        let mean_anomaly = if self.is_parabolic() {
+            let d = half.tan();
+            (d + d.powi(3) / 3.0) / 2.0
+        } else if e < 1.0 {
+            let eccentric = 2.0 * ((1.0 - e).sqrt() * half.sin()).atan2((1.0 + e).sqrt() * half.cos());
+            eccentric - e * eccentric.sin()
+        } else {
+            let hyperbolic = 2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * half.tan()).atanh();
+            e * hyperbolic.sinh() - hyperbolic
+        };
+
+        Scalar::<Float>::from(mean_anomaly) / self.mean_motion()
+    }
+
+    /// The angle from periapsis to the body at `time`, in the direction of motion.
+    pub fn true_anomaly_at(&self, time: Time) -> Angle {
+        let e = self.eccentricity;
+        let mean_anomaly = (self.mean_motion() * (time - self.time_of_periapsis)).value;
+
+        let true_anomaly = if self.is_parabolic() {
+            // Barker's equation, D + D³/3 = 2M, has a closed form solution
+            let b = 3.0 * mean_anomaly;
+            let y = (b + (b * b + 1.0).sqrt()).cbrt();
+            2.0 * (y - 1.0 / y).atan()
+        } else if e < 1.0 {
+            let eccentric = solve_elliptic(normalize(mean_anomaly), e);
+            2.0 * ((1.0 + e).sqrt() * (eccentric / 2.0).sin()).atan2((1.0 - e).sqrt() * (eccentric / 2.0).cos())
+        } else {
+            let hyperbolic = solve_hyperbolic(mean_anomaly, e);
+            2.0 * (((e + 1.0) / (e - 1.0)).sqrt() * (hyperbolic / 2.0).tanh()).atan()
+        };
+
+        Angle::in_radians(true_anomaly)
+    }
+
+    /// The position and velocity relative to the central body at `time`.
+    pub fn state_at(&self, time: Time) -> (Position, Velocity) {
+        let true_anomaly = self.true_anomaly_at(time);
+        let direction = if self.is_prograde() { 1.0 } else { -1.0 };
+        let e = self.eccentricity;
+        let p = self.semi_latus_rectum();
+
+        let radius = p / (1.0 + e * true_anomaly.cos());
+        let angle = self.argument_of_periapsis + true_anomaly * direction;
+        let outward = UnitVector::from((angle.cos(), angle.sin()));
+
+        let speed = (self.mu / p).sqrt();
+        let radial = speed * (e * true_anomaly.sin());
+        let transverse = speed * (direction * (1.0 + e * true_anomaly.cos()));
+
+        (outward * radius, outward * radial + outward.perpendicular() * transverse)
+    }
+}
+
+/// Wraps an angle in radians into (-π, π].
+fn normalize(angle: Float) -> Float {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped == -PI { PI } else { wrapped }
+}
+
+/// Solves Kepler's equation `M = E - e sin E` for the eccentric anomaly `E`.
+fn solve_elliptic(mean_anomaly: Float, e: Float) -> Float {
+    let mut eccentric = if e > 0.8 { PI * mean_anomaly.signum() } else { mean_anomaly };
+
+    for _ in 0..50 {
+        let step = (eccentric - e * eccentric.sin() - mean_anomaly) / (1.0 - e * eccentric.cos());
+        eccentric -= step;
+
+        if step.abs() < 1e-15 {
+            break;
+        }
+    }
+
+    eccentric
+}
+
+/// Solves the hyperbolic Kepler equation `M = e sinh H - H` for `H`.
+fn solve_hyperbolic(mean_anomaly: Float, e: Float) -> Float {
+    let mut hyperbolic = (mean_anomaly / e).asinh();
+
+    for _ in 0..100 {
+        let step = (e * hyperbolic.sinh() - hyperbolic - mean_anomaly) / (e * hyperbolic.cosh() - 1.0);
+        hyperbolic -= step;
+
+        if step.abs() < 1e-15 * hyperbolic.abs().max(1.0) {
+            break;
+        }
+    }
+
+    hyperbolic
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARTH: Float = 5.972_2e24;
+
+    fn earth() -> Mass {
+        Mass::in_kilograms(EARTH)
+    }
+
+    fn mu() -> Float {
+        (GRAVITATIONAL_CONSTANT * earth()).value
+    }
+
+    fn assert_state_eq(expected: (Position, Velocity), actual: (Position, Velocity)) {
+        let scale = expected.0.magnitude().value;
+        let speed = expected.1.magnitude().value;
+
+        assert!((expected.0 - actual.0).magnitude().value < 1e-8 * scale, "expected {:?}, got {:?}", expected.0, actual.0);
+        assert!((expected.1 - actual.1).magnitude().value < 1e-8 * speed, "expected {:?}, got {:?}", expected.1, actual.1);
+    }
+
+    #[test]
+    fn circular_orbit() {
+        let radius = 7.0e6;
+        let speed = (mu() / radius).sqrt();
+        let orbit = Orbit::from_state(earth(), Position::in_meters(radius, 0.0), Velocity::in_meters_per_second(0.0, speed), Time::zero()).unwrap();
+
+        assert!(orbit.eccentricity < 1e-12);
+        assert!(orbit.is_prograde());
+        assert_approx_eq!(radius, orbit.semi_major_axis().unwrap().value);
+        assert_approx_eq!(2.0 * PI * (radius.powi(3) / mu()).sqrt(), orbit.period().unwrap().value);
+        assert_approx_eq!(-mu() / (2.0 * radius), orbit.specific_energy().value);
+
+        let quarter = orbit.period().unwrap() / 4.0;
+        assert_state_eq(
+            (Position::in_meters(0.0, radius), Velocity::in_meters_per_second(-speed, 0.0)),
+            orbit.state_at(quarter),
+        );
+    }
+
+    #[test]
+    fn elements_round_trip_through_state() {
+        let orbits = [
+            (0.3, 0.0),
+            (0.97, 0.0),
+            (1.0, 0.0),
+            (2.5, 0.0),
+            (0.6, PI),
+            (1.8, PI),
+        ];
+
+        for &(eccentricity, inclination) in orbits.iter() {
+            let mut orbit = Orbit::from_periapsis(earth(), Length::in_meters(7.0e6), eccentricity, Angle::in_degrees(40.0), Time::in_seconds(100.0));
+            orbit.inclination = Angle::in_radians(inclination);
+
+            for &t in &[-3000.0, 0.0, 100.0, 2000.0, 40000.0] {
+                let time = Time::in_seconds(t);
+                let (position, velocity) = orbit.state_at(time);
+                let recovered = Orbit::from_state(earth(), position, velocity, time).unwrap();
+
+                assert_approx_eq!(orbit.eccentricity, recovered.eccentricity, 1e-7);
+                assert_approx_eq!(orbit.periapsis.value, recovered.periapsis.value, 1e-7);
+                assert_eq!(orbit.is_prograde(), recovered.is_prograde());
+                assert_state_eq(orbit.state_at(time + Time::in_seconds(500.0)), recovered.state_at(time + Time::in_seconds(500.0)));
+            }
+        }
+    }
+
+    #[test]
+    fn propagation_matches_numerical_integration() {
+        let orbit = Orbit::from_periapsis(earth(), Length::in_meters(7.0e6), 0.5, Angle::in_degrees(-30.0), Time::zero());
+        let gravity = |_: Time, state: &State| -> Acceleration {
+            let r = state.position.magnitude();
+            -(state.position / r) * (orbit.mu / (r * r))
+        };
+
+        let (position, velocity) = orbit.state_at(Time::zero());
+        let end = Integrator::RungeKutta4.integrate(State::new(position, velocity), Time::zero(), Time::in_seconds(1.0), 10_000, gravity);
+
+        assert_state_eq((end.position, end.velocity), orbit.state_at(Time::in_seconds(10_000.0)));
+    }
+
+    #[test]
+    fn open_orbits() {
+        let periapsis = Length::in_meters(7.0e6);
+
+        let parabola = Orbit::from_periapsis(earth(), periapsis, 1.0, Angle::zero(), Time::zero());
+        assert_eq!(None, parabola.semi_major_axis());
+        assert_eq!(None, parabola.period());
+        assert_eq!(EnergyDensity::zero(), parabola.specific_energy());
+
+        let (position, velocity) = parabola.state_at(Time::in_hours(5.0));
+        let speed_squared = velocity.magnitude_squared();
+        assert_approx_eq!(2.0 * mu() / position.magnitude().value, speed_squared);
+
+        let hyperbola = Orbit::from_periapsis(earth(), periapsis, 3.0, Angle::zero(), Time::zero());
+        assert!(hyperbola.semi_major_axis().unwrap() < Length::zero());
+        assert_eq!(None, hyperbola.apoapsis());
+
+        // approaching the asymptote at arccos(-1/e) after a long time
+        let late = hyperbola.true_anomaly_at(Time::in_days(1000.0));
+        assert_approx_eq!((-1.0 / 3.0_f64).acos(), late.value, 1e-3);
+    }
+
+    #[test]
+    fn highly_eccentric_orbit_returns_after_one_period() {
+        let orbit = Orbit::from_periapsis(earth(), Length::in_meters(7.0e6), 0.99, Angle::in_degrees(10.0), Time::zero());
+        let period = orbit.period().unwrap();
+
+        for &t in &[0.0, 0.01, 0.5, 0.93] {
+            let time = period * t;
+            assert_state_eq(orbit.state_at(time), orbit.state_at(time + period));
+        }
+
+        assert_approx_eq!(7.0e6 * 1.99 / 0.01, orbit.apoapsis().unwrap().value);
+    }
+
+    #[test]
+    fn radial_trajectory_has_no_orbit() {
+        let position = Position::in_meters(7.0e6, 0.0);
+
+        assert_eq!(None, Orbit::from_state(earth(), position, Velocity::in_meters_per_second(100.0, 0.0), Time::zero()));
+        assert_eq!(None, Orbit::from_state(earth(), Position::zero(), Velocity::in_meters_per_second(0.0, 1.0), Time::zero()));
+    }
+}
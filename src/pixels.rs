@@ -0,0 +1,297 @@
+use std::convert::TryFrom;
+use std::ops::*;
+use std::fmt::{Display, Formatter, Result};
+use crate::*;
+
+/// How a fractional pixel coordinate is converted to a whole pixel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rounding {
+    Floor,
+    Round,
+    Ceil,
+}
+
+impl Rounding {
+    #[inline]
+    fn apply(self, value: Float) -> i32 {
+        let value = match self {
+            Rounding::Floor => value.floor(),
+            Rounding::Round => value.round(),
+            Rounding::Ceil => value.ceil(),
+        };
+
+        value as i32
+    }
+}
+
+/// An integer pixel coordinate, for image and tile APIs.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Pixel {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pixel {
+    #[inline]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Pixel { x, y }
+    }
+
+    #[inline]
+    pub fn from_resolution(resolution: Resolution, rounding: Rounding) -> Self {
+        Pixel::new(rounding.apply(resolution.x.value), rounding.apply(resolution.y.value))
+    }
+
+    #[inline]
+    pub fn to_resolution(self) -> Resolution {
+        Resolution::in_pixels(self.x as Float, self.y as Float)
+    }
+
+    /// Whether the pixel lies inside a viewport of the given size, with the origin at `(0, 0)`.
+    #[inline]
+    pub fn is_within(self, viewport: Resolution) -> bool {
+        self.x >= 0
+            && self.y >= 0
+            && (self.x as Float) < viewport.x.value
+            && (self.y as Float) < viewport.y.value
+    }
+
+    /// Returns the pixel if it lies inside the viewport.
+    #[inline]
+    pub fn within(self, viewport: Resolution) -> Option<Self> {
+        if self.is_within(viewport) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Pixel {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({} px, {} px)", self.x, self.y)
+    }
+}
+
+impl From<(i32, i32)> for Pixel {
+    #[inline]
+    fn from((x, y): (i32, i32)) -> Self {
+        Pixel::new(x, y)
+    }
+}
+
+impl Add for Pixel {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Pixel::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Pixel {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Pixel::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+/// The pixels covered by the line from `start` to `end`, in order, using Bresenham's algorithm.
+///
+/// Returns no pixels if either end lies beyond the range of pixel coordinates.
+pub fn rasterize_line(start: Position, end: Position, scale: Scale) -> Vec<Pixel> {
+    let (start, end) = match (pixel_in_range(start, scale), pixel_in_range(end, scale)) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Vec::new(),
+    };
+
+    // widened so that lines spanning the whole pixel range cannot overflow
+    let dx = (end.0 - start.0).abs();
+    let dy = -(end.1 - start.1).abs();
+    let step_x = if start.0 < end.0 { 1 } else { -1 };
+    let step_y = if start.1 < end.1 { 1 } else { -1 };
+
+    let length = dx.max(-dy) + 1;
+    let mut pixels = Vec::with_capacity(length.min(MAX_LINE_CAPACITY) as usize);
+    let mut current = start;
+    let mut error = dx + dy;
+
+    loop {
+        // every step moves toward `end`, so the coordinates stay within i32
+        pixels.push(Pixel::new(current.0 as i32, current.1 as i32));
+
+        if current == end {
+            return pixels;
+        }
+
+        let doubled = 2 * error;
+
+        if doubled >= dy {
+            error += dy;
+            current.0 += step_x;
+        }
+
+        if doubled <= dx {
+            error += dx;
+            current.1 += step_y;
+        }
+    }
+}
+
+/// The most pixels reserved up front for a line, which grows past this as needed.
+const MAX_LINE_CAPACITY: i64 = 1 << 16;
+
+/// The nearest pixel to `position` as a widened coordinate, if it fits in a `Pixel`.
+fn pixel_in_range(position: Position, scale: Scale) -> Option<(i64, i64)> {
+    let resolution = position / scale;
+    Some((coordinate_in_range(resolution.x.value)?, coordinate_in_range(resolution.y.value)?))
+}
+
+/// The nearest whole pixel coordinate to `value`, widened, if it fits in a `Pixel`.
+fn coordinate_in_range(value: Float) -> Option<i64> {
+    let value = value.round();
+
+    if (i32::MIN as Float..=i32::MAX as Float).contains(&value) {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+/// The pixels on the outline of a circle, using the midpoint circle algorithm.
+///
+/// The result is sorted and contains no duplicates. Pixels beyond the range of pixel coordinates
+/// are left out, and there are none if the center or radius lies beyond that range.
+pub fn rasterize_circle(center: Position, radius: Length, scale: Scale) -> Vec<Pixel> {
+    let (center, radius) = match (pixel_in_range(center, scale), coordinate_in_range((radius / scale).value)) {
+        (Some(center), Some(radius)) => (center, radius),
+        _ => return Vec::new(),
+    };
+
+    // widened so that offsets from a center near the edge of the range cannot overflow
+    let mut pixels = Vec::new();
+    let mut x = radius;
+    let mut y = 0;
+    let mut error = 1 - radius;
+
+    while x >= y {
+        for &(px, py) in &[(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)] {
+            let (px, py) = (center.0 + px, center.1 + py);
+
+            if let (Ok(px), Ok(py)) = (i32::try_from(px), i32::try_from(py)) {
+                pixels.push(Pixel::new(px, py));
+            }
+        }
+
+        y += 1;
+
+        if error < 0 {
+            error += 2 * y + 1;
+        } else {
+            x -= 1;
+            error += 2 * (y - x) + 1;
+        }
+    }
+
+    pixels.sort();
+    pixels.dedup();
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_modes() {
+        let resolution = Resolution::in_pixels(1.5, -1.5);
+
+        assert_eq!(Pixel::new(1, -2), Pixel::from_resolution(resolution, Rounding::Floor));
+        assert_eq!(Pixel::new(2, -2), Pixel::from_resolution(resolution, Rounding::Round));
+        assert_eq!(Pixel::new(2, -1), Pixel::from_resolution(resolution, Rounding::Ceil));
+    }
+
+    #[test]
+    fn bounds_checking() {
+        let viewport = Resolution::in_pixels(800.0, 600.0);
+
+        assert!(Pixel::new(0, 0).is_within(viewport));
+        assert!(Pixel::new(799, 599).is_within(viewport));
+        assert!(!Pixel::new(800, 0).is_within(viewport));
+        assert!(!Pixel::new(0, -1).is_within(viewport));
+        assert_eq!(None, Pixel::new(0, 600).within(viewport));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("(3 px, -4 px)", Pixel::new(3, -4).to_string());
+    }
+
+    #[test]
+    fn rasterize_horizontal_line() {
+        let pixels = rasterize_line(Position::in_meters(0.0, 0.0), Position::in_meters(2.0, 0.0), Scale::from(0.5));
+
+        assert_eq!(vec![Pixel::new(0, 0), Pixel::new(1, 0), Pixel::new(2, 0), Pixel::new(3, 0), Pixel::new(4, 0)], pixels);
+    }
+
+    #[test]
+    fn rasterize_diagonal_line() {
+        let pixels = rasterize_line(Position::in_meters(3.0, 3.0), Position::in_meters(0.0, 0.0), Scale::from(1.0));
+
+        assert_eq!(vec![Pixel::new(3, 3), Pixel::new(2, 2), Pixel::new(1, 1), Pixel::new(0, 0)], pixels);
+    }
+
+    #[test]
+    fn rasterize_steep_line_is_connected() {
+        let pixels = rasterize_line(Position::in_meters(0.0, 0.0), Position::in_meters(2.0, 7.0), Scale::from(1.0));
+
+        assert_eq!(8, pixels.len());
+        for pair in pixels.windows(2) {
+            let step = pair[1] - pair[0];
+            assert!(step.x.abs() <= 1 && step.y.abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn rasterize_line_beyond_pixel_range() {
+        let far = Position::in_meters(1e12, 0.0);
+
+        assert!(rasterize_line(Position::zero(), far, Scale::from(1.0)).is_empty());
+        assert!(rasterize_line(Position::in_meters(Float::NAN, 0.0), Position::zero(), Scale::from(1.0)).is_empty());
+
+        // the widest line that fits still spans the full range without overflowing
+        let edge = i32::MAX as Float;
+        let pixels = rasterize_line(Position::in_meters(-edge, 0.0), Position::in_meters(-edge + 3.0, 1.0), Scale::from(1.0));
+        assert_eq!(Some(&Pixel::new(-i32::MAX + 3, 1)), pixels.last());
+    }
+
+    #[test]
+    fn rasterize_circle_beyond_pixel_range() {
+        let scale = Scale::from(1.0);
+
+        assert!(rasterize_circle(Position::in_meters(3e9, 0.0), Length::in_meters(5.0), scale).is_empty());
+        assert!(rasterize_circle(Position::zero(), Length::in_meters(1e12), scale).is_empty());
+        assert!(rasterize_circle(Position::zero(), Length::in_meters(Float::NAN), scale).is_empty());
+
+        // a circle straddling the edge of the range keeps only the pixels that fit
+        let edge = i32::MAX as Float;
+        let pixels = rasterize_circle(Position::in_meters(edge, 0.0), Length::in_meters(5.0), scale);
+        let whole = rasterize_circle(Position::zero(), Length::in_meters(5.0), scale);
+        assert!(pixels.contains(&Pixel::new(i32::MAX - 5, 0)));
+        assert!(pixels.contains(&Pixel::new(i32::MAX, 5)));
+        assert!(pixels.len() < whole.len());
+    }
+
+    #[test]
+    fn rasterize_circle_lies_on_radius() {
+        let pixels = rasterize_circle(Position::in_meters(10.0, 10.0), Length::in_meters(5.0), Scale::from(0.5));
+
+        assert!(pixels.contains(&Pixel::new(30, 20)));
+        assert!(pixels.contains(&Pixel::new(20, 10)));
+        for pixel in pixels {
+            let distance = (pixel - Pixel::new(20, 20)).to_resolution().magnitude().value;
+            assert!((distance - 10.0).abs() < 1.0);
+        }
+    }
+}
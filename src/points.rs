@@ -0,0 +1,249 @@
+use std::ops::*;
+use std::marker::PhantomData;
+use std::fmt::{Display, Formatter, Result, LowerExp};
+use crate::*;
+
+/// An absolute location in the frame `F`, as opposed to a `Vector`, which is a displacement.
+///
+/// Subtracting two points gives the `Vector` between them, and a `Vector` can be added to or
+/// subtracted from a point, but two points cannot be added together:
+///
+/// ```compile_fail
+/// use physics::*;
+///
+/// let a = Location::in_meters(1.0, 2.0);
+/// let b = Location::in_meters(3.0, 4.0);
+///
+/// let _ = a + b;
+/// ```
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Point<T, F = Unframed> {
+    pub x: Scalar<T>,
+    pub y: Scalar<T>,
+    frame: PhantomData<F>,
+}
+
+impl<T, F: Frame> Point<T, F> {
+    #[inline]
+    fn new<U: Into<Scalar<T>>>(x: U, y: U) -> Point<T, F> {
+        Point { x: x.into(), y: y.into(), frame: PhantomData }
+    }
+
+    #[inline]
+    pub const fn origin() -> Point<T, F> {
+        Point { x: Scalar::zero(), y: Scalar::zero(), frame: PhantomData }
+    }
+
+    /// The point displaced from the origin by `vector`.
+    #[inline]
+    pub fn from_vector(vector: Vector<T, F>) -> Point<T, F> {
+        Point::new(vector.x, vector.y)
+    }
+
+    /// The displacement of this point from the origin.
+    #[inline]
+    pub fn to_vector(self) -> Vector<T, F> {
+        Vector::from((self.x, self.y))
+    }
+}
+
+impl<T: Unit, F: Frame> Point<T, F> {
+    #[inline]
+    pub fn distance(&self, other: &Self) -> Scalar<T> {
+        (*other - *self).magnitude()
+    }
+
+    /// Linear interpolation, returning `self` at `t = 0` and `other` at `t = 1`.
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: Float) -> Self {
+        *self + (*other - *self) * t
+    }
+
+    #[inline]
+    pub fn midpoint(&self, other: &Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+
+    /// The mean of the given points, or `None` if there are none.
+    pub fn centroid<I: IntoIterator<Item=Self>>(points: I) -> Option<Self> {
+        let mut count = 0;
+        let mut sum = Vector::zero();
+
+        for point in points {
+            sum += point.to_vector();
+            count += 1;
+        }
+
+        if count == 0 {
+            None
+        } else {
+            Some(Point::from_vector(sum / count as Float))
+        }
+    }
+}
+
+impl<F: Frame> Point<Meters, F> {
+    #[inline]
+    pub fn in_meters<T: Into<Scalar<Meters>>>(x: T, y: T) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<F: Frame> Point<Pixels, F> {
+    #[inline]
+    pub fn in_pixels<T: Into<Scalar<Pixels>>>(x: T, y: T) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Unit, F: Frame> Display for Point<T, F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "({:.p$}, {:.p$})", self.x, self.y, p=precision)
+    }
+}
+
+impl<T: Unit, F: Frame> LowerExp for Point<T, F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "({:.p$e}, {:.p$e})", self.x, self.y, p=precision)
+    }
+}
+
+impl<T, F: Frame, U: Into<Scalar<T>>> From<(U, U)> for Point<T, F> {
+    #[inline]
+    fn from((x, y): (U, U)) -> Point<T, F> {
+        Point::new(x, y)
+    }
+}
+
+impl<T, F: Frame> Sub for Point<T, F> {
+    type Output = Vector<T, F>;
+    #[inline]
+    fn sub(self, rhs: Self) -> Vector<T, F> {
+        Vector::from((self.x - rhs.x, self.y - rhs.y))
+    }
+}
+
+impl<T, F: Frame> Add<Vector<T, F>> for Point<T, F> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Vector<T, F>) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T, F: Frame> Add<Point<T, F>> for Vector<T, F> {
+    type Output = Point<T, F>;
+    #[inline]
+    fn add(self, rhs: Point<T, F>) -> Point<T, F> {
+        rhs + self
+    }
+}
+
+impl<T, F: Frame> AddAssign<Vector<T, F>> for Point<T, F> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vector<T, F>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T, F: Frame> Sub<Vector<T, F>> for Point<T, F> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Vector<T, F>) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T, F: Frame> SubAssign<Vector<T, F>> for Point<T, F> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vector<T, F>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!("(1.50 m, 2.50 m)", Location::in_meters(1.5, 2.5).to_string());
+    }
+
+    #[test]
+    fn point_minus_point_is_vector() {
+        let a = Location::in_meters(5.0, 7.0);
+        let b = Location::in_meters(2.0, 3.0);
+
+        let displacement: Displacement = a - b;
+
+        assert_eq!(Position::in_meters(3.0, 4.0), displacement);
+    }
+
+    #[test]
+    fn point_plus_vector_is_point() {
+        let point = Location::in_meters(1.0, 2.0);
+        let displacement = Displacement::in_meters(3.0, 4.0);
+
+        assert_eq!(Location::in_meters(4.0, 6.0), point + displacement);
+        assert_eq!(Location::in_meters(4.0, 6.0), displacement + point);
+        assert_eq!(Location::in_meters(-2.0, -2.0), point - displacement);
+    }
+
+    #[test]
+    fn add_and_sub_assign() {
+        let mut point = Location::in_meters(1.0, 2.0);
+
+        point += Displacement::in_meters(3.0, 4.0);
+        assert_eq!(Location::in_meters(4.0, 6.0), point);
+
+        point -= Displacement::in_meters(1.0, 1.0);
+        assert_eq!(Location::in_meters(3.0, 5.0), point);
+    }
+
+    #[test]
+    fn vector_round_trip() {
+        let position = Position::in_meters(1.0, 2.0);
+
+        assert_eq!(position, Location::from_vector(position).to_vector());
+        assert_eq!(Location::origin() + position, Location::from_vector(position));
+    }
+
+    #[test]
+    fn distance() {
+        let a = Location::in_meters(1.0, 1.0);
+        let b = Location::in_meters(4.0, 5.0);
+
+        assert_eq!(Length::in_meters(5.0), a.distance(&b));
+    }
+
+    #[test]
+    fn midpoint() {
+        let a = Location::in_meters(1.0, 1.0);
+        let b = Location::in_meters(3.0, 5.0);
+
+        assert_eq!(Location::in_meters(2.0, 3.0), a.midpoint(&b));
+    }
+
+    #[test]
+    fn centroid() {
+        let points = vec![
+            Location::in_meters(0.0, 0.0),
+            Location::in_meters(4.0, 0.0),
+            Location::in_meters(2.0, 3.0),
+        ];
+
+        assert_eq!(Some(Location::in_meters(2.0, 1.0)), Point::centroid(points));
+    }
+
+    #[test]
+    fn centroid_of_nothing_is_none() {
+        assert_eq!(None, Point::<Meters>::centroid(vec![]));
+    }
+}
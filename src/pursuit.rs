@@ -0,0 +1,158 @@
+use crate::*;
+use crate::ballistics::intercept_times;
+
+/// Where and when a projectile fired in a straight line meets its target.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Intercept<F = Unframed> {
+    pub time: Time,
+    pub direction: Vector<Float, F>,
+    pub point: Vector<Meters, F>,
+}
+
+/// The earliest intercept of a target at `target` moving with `target_velocity` and an optional
+/// constant `target_acceleration`, by a projectile fired from `shooter` at `speed`.
+///
+/// Returns `None` if the projectile can never catch the target. A target already at the shooter
+/// is hit immediately, firing along its velocity.
+pub fn intercept<F: Frame>(
+    shooter: Vector<Meters, F>,
+    speed: Speed,
+    target: Vector<Meters, F>,
+    target_velocity: Vector<MetersPerSecond, F>,
+    target_acceleration: Option<Vector<MetersPerSecondSquared, F>>,
+) -> Option<Intercept<F>> {
+    let acceleration = target_acceleration.unwrap_or_else(Vector::zero);
+
+    if target == shooter {
+        return Some(Intercept {
+            time: Time::zero(),
+            direction: target_velocity.unit_vector().unwrap_or_else(|| Vector::from((1.0, 0.0))),
+            point: shooter,
+        });
+    }
+
+    let time = *intercept_times(target - shooter, target_velocity, acceleration, speed).first()?;
+    let point = target + target_velocity * time + acceleration * time * time * 0.5;
+
+    Some(Intercept {
+        time,
+        direction: (point - shooter).unit_vector()?,
+        point,
+    })
+}
+
+/// The nearest two bodies come to each other while moving in straight lines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Approach {
+    pub time: Time,
+    pub distance: Length,
+}
+
+/// When and how closely two bodies moving at constant velocities pass each other, counting
+/// only from now on. Bodies that are already separating are closest now.
+pub fn closest_approach<F: Frame>(
+    position: Vector<Meters, F>,
+    velocity: Vector<MetersPerSecond, F>,
+    other_position: Vector<Meters, F>,
+    other_velocity: Vector<MetersPerSecond, F>,
+) -> Approach {
+    let offset = other_position - position;
+    let closing = other_velocity - velocity;
+
+    let rate: EnergyDensity = closing.dot(closing);
+    let time = if rate == EnergyDensity::zero() {
+        Time::zero()
+    } else {
+        let along: Scalar<MetersSquaredPerSecond> = offset.dot(closing);
+        (-along / rate).max(Time::zero())
+    };
+
+    Approach {
+        time,
+        distance: (offset + closing * time).magnitude(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leading_a_moving_target() {
+        let speed = Speed::in_meters_per_second(50.0);
+        let target = Position::in_meters(100.0, 0.0);
+        let velocity = Velocity::in_meters_per_second(0.0, 30.0);
+
+        let hit = intercept(Position::zero(), speed, target, velocity, None).unwrap();
+
+        // 100² + (30 t)² = (50 t)², so t = 2.5 s
+        assert_approx_eq!(2.5, hit.time.value);
+        assert_approx_eq!(75.0, hit.point.y.value);
+        assert_approx_eq!(0.8, hit.direction.x.value);
+        assert_approx_eq!(0.6, hit.direction.y.value);
+
+        let still = intercept(Position::zero(), speed, target, Velocity::zero(), None).unwrap();
+        assert_approx_eq!(2.0, still.time.value);
+    }
+
+    #[test]
+    fn accelerating_target() {
+        let shooter = Position::in_meters(-20.0, 5.0);
+        let speed = Speed::in_meters_per_second(80.0);
+        let target = Position::in_meters(150.0, 60.0);
+        let velocity = Velocity::in_meters_per_second(-10.0, 15.0);
+        let acceleration = Acceleration::in_meters_per_second_squared(4.0, -2.0);
+
+        let hit = intercept(shooter, speed, target, velocity, Some(acceleration)).unwrap();
+        let projectile = shooter + hit.direction * (speed * hit.time);
+        let position = target + velocity * hit.time + acceleration * hit.time * hit.time * 0.5;
+
+        assert!((projectile - hit.point).magnitude().value < 1e-6);
+        assert!((position - hit.point).magnitude().value < 1e-6);
+
+        let coasting = intercept(shooter, speed, target, velocity, None).unwrap();
+        assert!(coasting.time != hit.time);
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        let speed = Speed::in_meters_per_second(10.0);
+        let target = Position::in_meters(100.0, 0.0);
+
+        assert_eq!(None, intercept(Position::zero(), speed, target, Velocity::in_meters_per_second(20.0, 0.0), None));
+
+        // a target that accelerates away stays out of reach
+        let running = Some(Acceleration::in_meters_per_second_squared(1.0, 0.0));
+        assert_eq!(None, intercept(Position::zero(), speed, target, Velocity::zero(), running));
+
+        let here = intercept(target, speed, target, Velocity::in_meters_per_second(0.0, -3.0), None).unwrap();
+        assert_eq!(Time::zero(), here.time);
+        assert_eq!(-1.0, here.direction.y.value);
+    }
+
+    #[test]
+    fn closest_approach_between_ships() {
+        let approach = closest_approach(
+            Position::zero(),
+            Velocity::in_meters_per_second(1.0, 0.0),
+            Position::in_meters(10.0, 12.0),
+            Velocity::in_meters_per_second(0.0, -1.0),
+        );
+
+        // the offset is (10, 12) - t (1, 1), closest at t = 11 with (-1, 1) remaining
+        assert_approx_eq!(11.0, approach.time.value);
+        assert_approx_eq!(2.0_f64.sqrt(), approach.distance.value);
+
+        let separating = closest_approach(
+            Position::zero(),
+            Velocity::in_meters_per_second(-1.0, 0.0),
+            Position::in_meters(3.0, 4.0),
+            Velocity::in_meters_per_second(1.0, 0.0),
+        );
+        assert_eq!(Time::zero(), separating.time);
+        assert_eq!(Length::in_meters(5.0), separating.distance);
+
+        let parallel = closest_approach(Position::zero(), Velocity::zero(), Position::in_meters(3.0, 4.0), Velocity::zero());
+        assert_eq!(Approach { time: Time::zero(), distance: Length::in_meters(5.0) }, parallel);
+    }
+}
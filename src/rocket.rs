@@ -0,0 +1,206 @@
+use crate::*;
+
+/// The change in speed from burning a rocket down from `wet_mass` to `dry_mass`.
+#[inline]
+pub fn rocket_equation(exhaust_velocity: ExhaustVelocity, wet_mass: Mass, dry_mass: Mass) -> Speed {
+    exhaust_velocity * (wet_mass / dry_mass).ln()
+}
+
+/// The propellant a rocket of `initial_mass` must burn to change its speed by `delta_v`.
+#[inline]
+pub fn propellant_mass(exhaust_velocity: ExhaustVelocity, initial_mass: Mass, delta_v: Speed) -> Mass {
+    initial_mass * (1.0 - (-(delta_v / exhaust_velocity)).exp())
+}
+
+#[inline]
+pub fn exhaust_velocity(specific_impulse: SpecificImpulse) -> ExhaustVelocity {
+    specific_impulse * STANDARD_GRAVITY
+}
+
+#[inline]
+pub fn specific_impulse(exhaust_velocity: ExhaustVelocity) -> SpecificImpulse {
+    exhaust_velocity / STANDARD_GRAVITY
+}
+
+/// An engine burning propellant at a constant rate.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Engine {
+    pub exhaust_velocity: ExhaustVelocity,
+    pub mass_flow: MassRate,
+}
+
+impl Engine {
+    #[inline]
+    pub fn new(exhaust_velocity: ExhaustVelocity, mass_flow: MassRate) -> Self {
+        Engine { exhaust_velocity, mass_flow }
+    }
+
+    #[inline]
+    pub fn from_specific_impulse(specific_impulse: SpecificImpulse, mass_flow: MassRate) -> Self {
+        Self::new(exhaust_velocity(specific_impulse), mass_flow)
+    }
+
+    /// An engine producing `thrust`, with the mass flow that requires.
+    #[inline]
+    pub fn from_thrust(thrust: Force, exhaust_velocity: ExhaustVelocity) -> Self {
+        Self::new(exhaust_velocity, thrust / exhaust_velocity)
+    }
+
+    #[inline]
+    pub fn specific_impulse(&self) -> SpecificImpulse {
+        specific_impulse(self.exhaust_velocity)
+    }
+
+    #[inline]
+    pub fn thrust(&self) -> Force {
+        self.mass_flow * self.exhaust_velocity
+    }
+
+    #[inline]
+    pub fn delta_v(&self, wet_mass: Mass, dry_mass: Mass) -> Speed {
+        rocket_equation(self.exhaust_velocity, wet_mass, dry_mass)
+    }
+
+    /// How long the engine must fire to change the speed of `initial_mass` by `delta_v`.
+    #[inline]
+    pub fn burn_time(&self, initial_mass: Mass, delta_v: Speed) -> Time {
+        propellant_mass(self.exhaust_velocity, initial_mass, delta_v) / self.mass_flow
+    }
+}
+
+/// One stage of a rocket, dropped once its propellant is spent.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stage {
+    pub engine: Engine,
+    pub dry_mass: Mass,
+    pub propellant: Mass,
+}
+
+impl Stage {
+    #[inline]
+    pub fn new(engine: Engine, dry_mass: Mass, propellant: Mass) -> Self {
+        Stage { engine, dry_mass, propellant }
+    }
+
+    #[inline]
+    pub fn wet_mass(&self) -> Mass {
+        self.dry_mass + self.propellant
+    }
+
+    #[inline]
+    pub fn burn_time(&self) -> Time {
+        self.propellant / self.engine.mass_flow
+    }
+}
+
+/// A payload on top of a stack of stages, with the first stage to fire first in the list.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Vehicle {
+    pub payload: Mass,
+    pub stages: Vec<Stage>,
+}
+
+impl Vehicle {
+    #[inline]
+    pub fn new(payload: Mass) -> Self {
+        Vehicle { payload, stages: vec![] }
+    }
+
+    /// Adds a stage below the existing ones, so that it fires first.
+    #[inline]
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        self.stages.insert(0, stage);
+        self
+    }
+
+    #[inline]
+    pub fn total_mass(&self) -> Mass {
+        self.stages.iter().fold(self.payload, |total, stage| total + stage.wet_mass())
+    }
+
+    /// The delta-v from each stage, carrying everything above it.
+    pub fn stage_delta_v(&self) -> Vec<Speed> {
+        let mut mass = self.total_mass();
+
+        self.stages
+            .iter()
+            .map(|stage| {
+                let burnt = mass - stage.propellant;
+                let delta_v = stage.engine.delta_v(mass, burnt);
+                mass = burnt - stage.dry_mass;
+                delta_v
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub fn total_delta_v(&self) -> Speed {
+        self.stage_delta_v().into_iter().fold(Speed::zero(), |total, delta_v| total + delta_v)
+    }
+
+    #[inline]
+    pub fn burn_time(&self) -> Time {
+        self.stages.iter().fold(Time::zero(), |total, stage| total + stage.burn_time())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kg(value: Float) -> Mass {
+        Mass::in_kilograms(value)
+    }
+
+    fn engine() -> Engine {
+        Engine::from_specific_impulse(Time::in_seconds(300.0), MassRate::in_kilograms_per_second(100.0))
+    }
+
+    #[test]
+    fn specific_impulse_and_thrust() {
+        let engine = engine();
+
+        assert_approx_eq!(2941.995, engine.exhaust_velocity.value);
+        assert_approx_eq!(300.0, engine.specific_impulse().value);
+        assert_approx_eq!(294_199.5, engine.thrust().value);
+
+        let same = Engine::from_thrust(engine.thrust(), engine.exhaust_velocity);
+        assert_approx_eq!(100.0, same.mass_flow.value);
+    }
+
+    #[test]
+    fn rocket_equation_and_burn_time() {
+        let engine = engine();
+        let e = std::f64::consts::E;
+
+        assert_approx_eq!(engine.exhaust_velocity.value, engine.delta_v(kg(1000.0 * e), kg(1000.0)).value);
+
+        // burning for the returned time spends the propellant that gives the requested delta-v
+        let initial = kg(10_000.0);
+        let delta_v = Speed::in_meters_per_second(2000.0);
+        let burn = engine.burn_time(initial, delta_v);
+        let spent = engine.mass_flow * burn;
+
+        assert_approx_eq!(delta_v.value, engine.delta_v(initial, initial - spent).value);
+        assert_eq!(Time::zero(), engine.burn_time(initial, Speed::zero()));
+    }
+
+    #[test]
+    fn staging() {
+        let upper = Stage::new(engine(), kg(1000.0), kg(9000.0));
+        let lower = Stage::new(engine(), kg(5000.0), kg(45_000.0));
+        let vehicle = Vehicle::new(kg(500.0)).with_stage(upper).with_stage(lower);
+
+        assert_eq!(kg(60_500.0), vehicle.total_mass());
+        assert_eq!(Time::in_seconds(540.0), vehicle.burn_time());
+
+        let ve = engine().exhaust_velocity.value;
+        let stages = vehicle.stage_delta_v();
+        assert_approx_eq!(ve * (60_500.0_f64 / 15_500.0).ln(), stages[0].value);
+        assert_approx_eq!(ve * (10_500.0_f64 / 1500.0).ln(), stages[1].value);
+
+        // dropping dead weight beats carrying it as one stage
+        let single = engine().delta_v(kg(60_500.0), kg(6500.0));
+        assert!(vehicle.total_delta_v() > single);
+    }
+}
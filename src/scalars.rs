@@ -1,10 +1,11 @@
 use std::ops::*;
 use std::marker::PhantomData;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result, LowerExp};
 use crate::*;
 use std::f64::consts::PI;
 
-#[derive(Debug, Default, Copy, Clone, PartialOrd)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct Scalar<T> {
     pub value: Float,
     marker: PhantomData<T>,
@@ -17,7 +18,7 @@ impl<T: Unit> Display for Scalar<T> {
 
         write!(f, "{:.*}", precision, self.value)?;
 
-        for s in T::symbol() {
+        if let Some(s) = T::symbol() {
             let _ = write!(f, " {}", s);
         }
 
@@ -31,7 +32,7 @@ impl<T: Unit> LowerExp for Scalar<T> {
 
         write!(f, "{:.*e}", precision, self.value)?;
 
-        for s in T::symbol() {
+        if let Some(s) = T::symbol() {
             let _ = write!(f, " {}", s);
         }
 
@@ -49,6 +50,21 @@ impl<T> Scalar<T> {
     pub const fn zero() -> Self {
         Self::new(0.0)
     }
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self::new(self.value.abs())
+    }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.value.min(other.value))
+    }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.value.max(other.value))
+    }
 }
 
 impl<T> Neg for Scalar<T> {
@@ -73,6 +89,13 @@ impl<T> PartialEq for Scalar<T> {
     }
 }
 
+impl<T> PartialOrd for Scalar<T> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&rhs.value)
+    }
+}
+
 impl<T> Add for Scalar<T> {
     type Output = Scalar<T>;
     #[inline]
@@ -119,6 +142,14 @@ impl<T> Mul<Scalar<T>> for Float {
     }
 }
 
+impl<T> Mul<Scalar<T>> for Scalar<Float> {
+    type Output = Scalar<T>;
+    #[inline]
+    fn mul(self, rhs: Scalar<T>) -> Scalar<T> {
+        rhs * self.value
+    }
+}
+
 impl<T> MulAssign<Float> for Scalar<T> {
     #[inline]
     fn mul_assign(&mut self, rhs: Float) {
@@ -204,6 +235,13 @@ impl Scalar<Kelvin> {
     }
 }
 
+impl Scalar<Moles> {
+    #[inline]
+    pub fn in_moles(value: Float) -> Self {
+        value.into()
+    }
+}
+
 impl Scalar<Newtons> {
     #[inline]
     pub fn in_newtons(value: Float) -> Self {
@@ -211,6 +249,13 @@ impl Scalar<Newtons> {
     }
 }
 
+impl Scalar<Pascals> {
+    #[inline]
+    pub fn in_pascals(value: Float) -> Self {
+        value.into()
+    }
+}
+
 impl Scalar<Joules> {
     #[inline]
     pub fn in_joules(value: Float) -> Self {
@@ -247,6 +292,13 @@ impl Scalar<MetersCubed> {
     pub fn in_meters_cubed(value: Float) -> Self { value.into() }
 }
 
+impl Scalar<KilogramsPerSecond> {
+    #[inline]
+    pub fn in_kilograms_per_second(value: Float) -> Self {
+        value.into()
+    }
+}
+
 impl Scalar<KilogramsPerMeterCubed> {
     #[inline]
     pub fn in_kilograms_per_meter_cubed(value: Float) -> Self {
@@ -254,6 +306,34 @@ impl Scalar<KilogramsPerMeterCubed> {
     }
 }
 
+impl Scalar<KilogramsPerMeterSquared> {
+    #[inline]
+    pub fn in_kilograms_per_meter_squared(value: Float) -> Self {
+        value.into()
+    }
+}
+
+impl Scalar<KilogramMetersSquared> {
+    #[inline]
+    pub fn in_kilogram_meters_squared(value: Float) -> Self {
+        value.into()
+    }
+}
+
+impl Scalar<KilogramMetersPerSecond> {
+    #[inline]
+    pub fn in_kilogram_meters_per_second(value: Float) -> Self {
+        value.into()
+    }
+}
+
+impl Scalar<RadiansPerSecond> {
+    #[inline]
+    pub fn in_radians_per_second(value: Float) -> Self {
+        value.into()
+    }
+}
+
 impl Scalar<Radians> {
     #[inline]
     pub fn in_radians(angle: Float) -> Self { angle.into() }
@@ -435,6 +515,16 @@ mod tests {
         assert_eq!(0.5, t1 / t2);
     }
 
+    #[test]
+    fn abs_min_max() {
+        let lhs = Time::from(-2.0);
+        let rhs = Time::from(3.0);
+
+        assert_eq!(Time::from(2.0), lhs.abs());
+        assert_eq!(lhs, lhs.min(rhs));
+        assert_eq!(rhs, lhs.max(rhs));
+    }
+
     #[test]
     fn time_tests() {
         assert_eq!(Time::in_seconds(60.0), Time::in_minutes(1.0));
@@ -0,0 +1,694 @@
+use std::collections::HashMap;
+use crate::*;
+
+/// A handle to an entry in a spatial index.
+///
+/// Handles are reused once their entry is removed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpatialId(pub(crate) usize);
+
+/// An entry found by a distance query.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Neighbor<'a, T, F = Unframed> {
+    pub id: SpatialId,
+    pub position: Vector<Meters, F>,
+    pub value: &'a T,
+    pub distance: Length,
+}
+
+/// Storage shared by the indexes, with handles that stay valid while other entries come and go.
+#[derive(Debug, Clone)]
+struct Entries<T, F> {
+    slots: Vec<Option<(Vector<Meters, F>, T)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T, F: Frame> Entries<T, F> {
+    fn new() -> Self {
+        Entries { slots: vec![], free: vec![], len: 0 }
+    }
+
+    fn insert(&mut self, position: Vector<Meters, F>, value: T) -> SpatialId {
+        self.len += 1;
+
+        match self.free.pop() {
+            Some(index) => {
+                self.slots[index] = Some((position, value));
+                SpatialId(index)
+            }
+            None => {
+                self.slots.push(Some((position, value)));
+                SpatialId(self.slots.len() - 1)
+            }
+        }
+    }
+
+    fn remove(&mut self, id: SpatialId) -> Option<(Vector<Meters, F>, T)> {
+        let entry = self.slots.get_mut(id.0)?.take()?;
+        self.free.push(id.0);
+        self.len -= 1;
+        Some(entry)
+    }
+
+    fn get(&self, id: SpatialId) -> Option<&(Vector<Meters, F>, T)> {
+        self.slots.get(id.0)?.as_ref()
+    }
+
+    fn get_mut(&mut self, id: SpatialId) -> Option<&mut (Vector<Meters, F>, T)> {
+        self.slots.get_mut(id.0)?.as_mut()
+    }
+
+    fn position(&self, id: SpatialId) -> Vector<Meters, F> {
+        self.get(id).expect("index refers to a removed entry").0
+    }
+
+    fn neighbor(&self, id: SpatialId, point: Vector<Meters, F>) -> Neighbor<'_, T, F> {
+        let (position, value) = self.get(id).expect("index refers to a removed entry");
+        Neighbor { id, position: *position, value, distance: (*position - point).magnitude() }
+    }
+
+    fn iter(&self) -> impl Iterator<Item=SpatialId> + '_ {
+        self.slots.iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.is_some())
+            .map(|(index, _)| SpatialId(index))
+    }
+}
+
+/// Orders neighbors nearest first, breaking ties by handle so results are repeatable.
+fn sort_neighbors<T, F>(neighbors: &mut [Neighbor<T, F>]) {
+    neighbors.sort_by(|a, b| a.distance.value.total_cmp(&b.distance.value).then(a.id.cmp(&b.id)));
+}
+
+/// A uniform grid of square cells, storing only the cells that are occupied.
+///
+/// Works best when entries are spread fairly evenly and queries are about the size of a cell.
+#[derive(Debug, Clone)]
+pub struct HashGrid<T, F = Unframed> {
+    cell_size: Length,
+    cells: HashMap<(i64, i64), Vec<SpatialId>>,
+    entries: Entries<T, F>,
+}
+
+impl<T, F: Frame> HashGrid<T, F> {
+    /// # Panics
+    /// If `cell_size` is not positive.
+    pub fn new(cell_size: Length) -> Self {
+        assert!(cell_size > Length::zero(), "cell size must be positive: {}", cell_size);
+        HashGrid { cell_size, cells: HashMap::new(), entries: Entries::new() }
+    }
+
+    #[inline]
+    pub fn cell_size(&self) -> Length {
+        self.cell_size
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn get(&self, id: SpatialId) -> Option<(Vector<Meters, F>, &T)> {
+        self.entries.get(id).map(|(position, value)| (*position, value))
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, id: SpatialId) -> Option<&mut T> {
+        self.entries.get_mut(id).map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, position: Vector<Meters, F>, value: T) -> SpatialId {
+        let id = self.entries.insert(position, value);
+        self.cells.entry(self.cell(position)).or_default().push(id);
+        id
+    }
+
+    pub fn remove(&mut self, id: SpatialId) -> Option<T> {
+        let (position, value) = self.entries.remove(id)?;
+        self.remove_from_cell(id, self.cell(position));
+        Some(value)
+    }
+
+    /// Moves an entry, returning `false` if it does not exist.
+    pub fn move_to(&mut self, id: SpatialId, position: Vector<Meters, F>) -> bool {
+        let old = match self.entries.get_mut(id) {
+            Some(entry) => std::mem::replace(&mut entry.0, position),
+            None => return false,
+        };
+
+        let (from, to) = (self.cell(old), self.cell(position));
+        if from != to {
+            self.remove_from_cell(id, from);
+            self.cells.entry(to).or_default().push(id);
+        }
+
+        true
+    }
+
+    /// Every entry inside or on the edge of the box, ordered by handle.
+    pub fn query_aabb(&self, aabb: &Aabb<Meters, F>) -> Vec<(SpatialId, &T)> {
+        let mut found = self.candidates(aabb)
+            .filter(|&id| aabb.contains(self.entries.position(id)))
+            .collect::<Vec<_>>();
+
+        found.sort();
+        found.into_iter().map(|id| (id, &self.entries.get(id).unwrap().1)).collect()
+    }
+
+    /// Every entry within `radius` of `center`, nearest first.
+    pub fn query_radius(&self, center: Vector<Meters, F>, radius: Length) -> Vec<Neighbor<'_, T, F>> {
+        let bounds = Aabb::from_center(center, Vector::from((radius, radius)));
+
+        let mut found = self.candidates(&bounds)
+            .map(|id| self.entries.neighbor(id, center))
+            .filter(|neighbor| neighbor.distance <= radius)
+            .collect::<Vec<_>>();
+
+        sort_neighbors(&mut found);
+        found
+    }
+
+    /// The `k` entries nearest to `point`, nearest first.
+    ///
+    /// Searches outward ring by ring from the cell containing `point`.
+    pub fn nearest(&self, point: Vector<Meters, F>, k: usize) -> Vec<Neighbor<'_, T, F>> {
+        let mut found = Vec::new();
+        if k == 0 {
+            return found;
+        }
+
+        let (x, y) = self.cell(point);
+        let mut visited = 0;
+
+        for ring in 0_i64.. {
+            // once a ring has more cells than are occupied, checking every entry is cheaper
+            if ring * 8 > self.cells.len() as i64 {
+                found = self.entries.iter().map(|id| self.entries.neighbor(id, point)).collect();
+                sort_neighbors(&mut found);
+                found.truncate(k);
+                return found;
+            }
+
+            for cell in ring_cells(x, y, ring) {
+                if let Some(ids) = self.cells.get(&cell) {
+                    visited += ids.len();
+                    found.extend(ids.iter().map(|&id| self.entries.neighbor(id, point)));
+                }
+            }
+
+            sort_neighbors(&mut found);
+            found.truncate(k);
+
+            // anything not yet visited is at least this far away
+            let reach = self.cell_size * ring as Float;
+            let complete = found.len() == k && found[k - 1].distance <= reach;
+
+            if complete || visited == self.len() {
+                break;
+            }
+        }
+
+        found
+    }
+
+    #[inline]
+    fn cell(&self, position: Vector<Meters, F>) -> (i64, i64) {
+        (
+            (position.x / self.cell_size).floor() as i64,
+            (position.y / self.cell_size).floor() as i64,
+        )
+    }
+
+    fn remove_from_cell(&mut self, id: SpatialId, cell: (i64, i64)) {
+        if let Some(ids) = self.cells.get_mut(&cell) {
+            ids.retain(|&other| other != id);
+
+            if ids.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    /// The entries in every cell overlapping the box.
+    fn candidates<'a>(&'a self, aabb: &Aabb<Meters, F>) -> Box<dyn Iterator<Item=SpatialId> + 'a> {
+        let (min_x, min_y) = self.cell(aabb.min);
+        let (max_x, max_y) = self.cell(aabb.max);
+        let width = max_x.saturating_sub(min_x).saturating_add(1);
+        let height = max_y.saturating_sub(min_y).saturating_add(1);
+        let area = width.saturating_mul(height);
+
+        // for large boxes it is cheaper to walk the occupied cells
+        if area > self.cells.len() as i64 {
+            return Box::new(self.cells.iter()
+                .filter(move |((x, y), _)| (min_x..=max_x).contains(x) && (min_y..=max_y).contains(y))
+                .flat_map(|(_, ids)| ids.iter().copied()));
+        }
+
+        Box::new((min_x..=max_x)
+            .flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+            .filter_map(move |cell| self.cells.get(&cell))
+            .flat_map(|ids| ids.iter().copied()))
+    }
+}
+
+/// The cells on the square ring `ring` cells away from `(x, y)`.
+fn ring_cells(x: i64, y: i64, ring: i64) -> Vec<(i64, i64)> {
+    if ring == 0 {
+        return vec![(x, y)];
+    }
+
+    let horizontal = (-ring..=ring).flat_map(|dx| vec![(x + dx, y - ring), (x + dx, y + ring)]);
+    let vertical = (1 - ring..ring).flat_map(|dy| vec![(x - ring, y + dy), (x + ring, y + dy)]);
+
+    horizontal.chain(vertical).collect()
+}
+
+/// The number of entries a quadtree node holds before it splits.
+const NODE_CAPACITY: usize = 8;
+
+/// A region quadtree that subdivides where entries are dense.
+///
+/// Nodes stop splitting once they would be smaller than the minimum cell size. Entries outside
+/// the bounds are kept at the root, so they are still found but not accelerated.
+#[derive(Debug, Clone)]
+pub struct Quadtree<T, F = Unframed> {
+    root: Node<F>,
+    min_cell_size: Length,
+    entries: Entries<T, F>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<F> {
+    bounds: Aabb<Meters, F>,
+    ids: Vec<SpatialId>,
+    children: Option<Box<[Node<F>; 4]>>,
+}
+
+impl<T, F: Frame> Quadtree<T, F> {
+    /// # Panics
+    /// If `min_cell_size` is not positive.
+    pub fn new(bounds: Aabb<Meters, F>, min_cell_size: Length) -> Self {
+        assert!(min_cell_size > Length::zero(), "minimum cell size must be positive: {}", min_cell_size);
+        Quadtree { root: Node::new(bounds), min_cell_size, entries: Entries::new() }
+    }
+
+    #[inline]
+    pub fn bounds(&self) -> Aabb<Meters, F> {
+        self.root.bounds
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn get(&self, id: SpatialId) -> Option<(Vector<Meters, F>, &T)> {
+        self.entries.get(id).map(|(position, value)| (*position, value))
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, id: SpatialId) -> Option<&mut T> {
+        self.entries.get_mut(id).map(|(_, value)| value)
+    }
+
+    pub fn insert(&mut self, position: Vector<Meters, F>, value: T) -> SpatialId {
+        let id = self.entries.insert(position, value);
+        self.root.insert(id, position, &self.entries, self.min_cell_size);
+        id
+    }
+
+    pub fn remove(&mut self, id: SpatialId) -> Option<T> {
+        let (position, value) = self.entries.remove(id)?;
+        self.root.remove(id, position);
+        Some(value)
+    }
+
+    /// Moves an entry, returning `false` if it does not exist.
+    pub fn move_to(&mut self, id: SpatialId, position: Vector<Meters, F>) -> bool {
+        let old = match self.entries.get_mut(id) {
+            Some(entry) => std::mem::replace(&mut entry.0, position),
+            None => return false,
+        };
+
+        self.root.remove(id, old);
+        self.root.insert(id, position, &self.entries, self.min_cell_size);
+        true
+    }
+
+    /// Every entry inside or on the edge of the box, ordered by handle.
+    pub fn query_aabb(&self, aabb: &Aabb<Meters, F>) -> Vec<(SpatialId, &T)> {
+        let mut found = vec![];
+        self.root.query_aabb(aabb, &self.entries, &mut found);
+
+        found.sort();
+        found.into_iter().map(|id| (id, &self.entries.get(id).unwrap().1)).collect()
+    }
+
+    /// Every entry within `radius` of `center`, nearest first.
+    pub fn query_radius(&self, center: Vector<Meters, F>, radius: Length) -> Vec<Neighbor<'_, T, F>> {
+        let mut found = vec![];
+        self.root.query_radius(center, radius, &self.entries, &mut found);
+
+        sort_neighbors(&mut found);
+        found
+    }
+
+    /// The `k` entries nearest to `point`, nearest first.
+    ///
+    /// Visits nearer nodes first and skips any node farther away than the `k`th best so far.
+    pub fn nearest(&self, point: Vector<Meters, F>, k: usize) -> Vec<Neighbor<'_, T, F>> {
+        let mut found = vec![];
+        if k > 0 {
+            self.root.nearest(point, k, &self.entries, &mut found);
+        }
+        found
+    }
+}
+
+impl<F: Frame> Node<F> {
+    fn new(bounds: Aabb<Meters, F>) -> Self {
+        Node { bounds, ids: vec![], children: None }
+    }
+
+    /// The child that would hold `position`, counting from the south-west corner.
+    fn quadrant(&self, position: Vector<Meters, F>) -> Option<usize> {
+        if !self.bounds.contains(position) {
+            return None;
+        }
+
+        let center = self.bounds.center();
+        let east = (position.x >= center.x) as usize;
+        let north = (position.y >= center.y) as usize;
+
+        Some(east + 2 * north)
+    }
+
+    fn insert<T>(&mut self, id: SpatialId, position: Vector<Meters, F>, entries: &Entries<T, F>, min_cell_size: Length) {
+        let quadrant = self.quadrant(position);
+
+        if let (Some(children), Some(index)) = (&mut self.children, quadrant) {
+            children[index].insert(id, position, entries, min_cell_size);
+            return;
+        }
+
+        self.ids.push(id);
+
+        if self.children.is_none() && self.ids.len() > NODE_CAPACITY && self.can_split(min_cell_size) {
+            self.split(entries, min_cell_size);
+        }
+    }
+
+    /// Whether the children would be at least `min_cell_size` across and strictly smaller than
+    /// this node, which stops splitting once the bounds are too small to halve in floating point.
+    fn can_split(&self, min_cell_size: Length) -> bool {
+        let Aabb { min, max } = self.bounds;
+        let center = self.bounds.center();
+
+        self.bounds.width() / 2.0 >= min_cell_size
+            && min.x < center.x && center.x < max.x
+            && min.y < center.y && center.y < max.y
+    }
+
+    fn split<T>(&mut self, entries: &Entries<T, F>, min_cell_size: Length) {
+        let Aabb { min, max } = self.bounds;
+        let center = self.bounds.center();
+
+        let mut children = Box::new([
+            Node::new(Aabb::new(min, center)),
+            Node::new(Aabb::new(Vector::from((center.x, min.y)), Vector::from((max.x, center.y)))),
+            Node::new(Aabb::new(Vector::from((min.x, center.y)), Vector::from((center.x, max.y)))),
+            Node::new(Aabb::new(center, max)),
+        ]);
+
+        let mut remaining = vec![];
+
+        for id in std::mem::take(&mut self.ids) {
+            let position = entries.position(id);
+
+            match self.quadrant(position) {
+                Some(index) => children[index].insert(id, position, entries, min_cell_size),
+                None => remaining.push(id),
+            }
+        }
+
+        self.ids = remaining;
+        self.children = Some(children);
+    }
+
+    fn remove(&mut self, id: SpatialId, position: Vector<Meters, F>) {
+        let quadrant = self.quadrant(position);
+
+        match (&mut self.children, quadrant) {
+            (Some(children), Some(index)) => children[index].remove(id, position),
+            _ => self.ids.retain(|&other| other != id),
+        }
+
+        self.collapse();
+    }
+
+    /// Pulls the entries of leaf children back up once they would fit in this node.
+    fn collapse(&mut self) {
+        let fits = match &self.children {
+            Some(children) => {
+                children.iter().all(|child| child.children.is_none())
+                    && self.ids.len() + children.iter().map(|child| child.ids.len()).sum::<usize>() <= NODE_CAPACITY
+            }
+            None => false,
+        };
+
+        if fits {
+            if let Some(children) = self.children.take() {
+                for child in children.iter() {
+                    self.ids.extend_from_slice(&child.ids);
+                }
+            }
+        }
+    }
+
+    fn query_aabb<T>(&self, aabb: &Aabb<Meters, F>, entries: &Entries<T, F>, found: &mut Vec<SpatialId>) {
+        found.extend(self.ids.iter().filter(|&&id| aabb.contains(entries.position(id))));
+
+        if let Some(children) = &self.children {
+            for child in children.iter().filter(|child| child.bounds.intersects(aabb)) {
+                child.query_aabb(aabb, entries, found);
+            }
+        }
+    }
+
+    fn query_radius<'a, T>(&self, center: Vector<Meters, F>, radius: Length, entries: &'a Entries<T, F>, found: &mut Vec<Neighbor<'a, T, F>>) {
+        found.extend(self.ids.iter()
+            .map(|&id| entries.neighbor(id, center))
+            .filter(|neighbor| neighbor.distance <= radius));
+
+        if let Some(children) = &self.children {
+            for child in children.iter().filter(|child| child.bounds.distance_to_point(center) <= radius) {
+                child.query_radius(center, radius, entries, found);
+            }
+        }
+    }
+
+    fn nearest<'a, T>(&self, point: Vector<Meters, F>, k: usize, entries: &'a Entries<T, F>, found: &mut Vec<Neighbor<'a, T, F>>) {
+        found.extend(self.ids.iter().map(|&id| entries.neighbor(id, point)));
+        sort_neighbors(found);
+        found.truncate(k);
+
+        if let Some(children) = &self.children {
+            let mut order = children.iter()
+                .map(|child| (child.bounds.distance_to_point(point), child))
+                .collect::<Vec<_>>();
+
+            order.sort_by(|a, b| a.0.value.total_cmp(&b.0.value));
+
+            for (distance, child) in order {
+                if found.len() == k && distance > found[k - 1].distance {
+                    break;
+                }
+
+                child.nearest(point, k, entries, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{p, scattered};
+
+    fn grid(points: &[Position]) -> HashGrid<usize> {
+        let mut grid = HashGrid::new(Length::in_meters(7.0));
+        for (i, &point) in points.iter().enumerate() {
+            grid.insert(point, i);
+        }
+        grid
+    }
+
+    fn quadtree(points: &[Position]) -> Quadtree<usize> {
+        let mut tree = Quadtree::new(Aabb::new(p(0.0, 0.0), p(100.0, 100.0)), Length::in_meters(1.0));
+        for (i, &point) in points.iter().enumerate() {
+            tree.insert(point, i);
+        }
+        tree
+    }
+
+    fn brute_force_nearest(points: &[Position], point: Position, k: usize) -> Vec<usize> {
+        let mut indices = (0..points.len()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| {
+            let da = (points[a] - point).magnitude();
+            let db = (points[b] - point).magnitude();
+            da.value.total_cmp(&db.value).then(a.cmp(&b))
+        });
+        indices.truncate(k);
+        indices
+    }
+
+    fn values<T: Copy, F>(neighbors: &[Neighbor<T, F>]) -> Vec<T> {
+        neighbors.iter().map(|neighbor| *neighbor.value).collect()
+    }
+
+    #[test]
+    fn queries_match_brute_force() {
+        let points = scattered(500, 100.0);
+        let grid = grid(&points);
+        let tree = quadtree(&points);
+
+        let aabb = Aabb::new(p(20.0, 30.0), p(45.0, 38.0));
+        let inside = (0..points.len()).filter(|&i| aabb.contains(points[i])).collect::<Vec<_>>();
+        assert!(!inside.is_empty());
+
+        let from_grid = grid.query_aabb(&aabb).into_iter().map(|(_, &i)| i).collect::<Vec<_>>();
+        let from_tree = tree.query_aabb(&aabb).into_iter().map(|(_, &i)| i).collect::<Vec<_>>();
+        assert_eq!(inside, from_grid);
+        assert_eq!(inside, from_tree);
+
+        let center = p(60.0, 40.0);
+        let radius = Length::in_meters(12.0);
+        let mut within = brute_force_nearest(&points, center, points.len());
+        within.retain(|&i| (points[i] - center).magnitude() <= radius);
+
+        assert_eq!(within, values(&grid.query_radius(center, radius)));
+        assert_eq!(within, values(&tree.query_radius(center, radius)));
+
+        for &(point, k) in &[(p(50.0, 50.0), 10), (p(-30.0, 120.0), 3), (p(99.0, 1.0), 1)] {
+            let expected = brute_force_nearest(&points, point, k);
+
+            assert_eq!(expected, values(&grid.nearest(point, k)));
+            assert_eq!(expected, values(&tree.nearest(point, k)));
+        }
+    }
+
+    #[test]
+    fn nearest_reports_distance() {
+        let points = vec![p(0.0, 0.0), p(3.0, 4.0), p(10.0, 0.0)];
+        let grid = grid(&points);
+        let tree = quadtree(&points);
+
+        for neighbors in [grid.nearest(p(0.0, 0.0), 2), tree.nearest(p(0.0, 0.0), 2)].iter() {
+            assert_eq!(vec![Length::zero(), Length::in_meters(5.0)], neighbors.iter().map(|n| n.distance).collect::<Vec<_>>());
+        }
+
+        assert_eq!(3, grid.nearest(p(0.0, 0.0), 10).len());
+        assert!(tree.nearest(p(0.0, 0.0), 0).is_empty());
+    }
+
+    #[test]
+    fn nearest_to_not_a_number_does_not_panic() {
+        let points = vec![p(0.0, 0.0), p(3.0, 4.0), p(10.0, 0.0)];
+
+        assert_eq!(2, grid(&points).nearest(p(Float::NAN, 0.0), 2).len());
+        assert_eq!(2, quadtree(&points).nearest(p(Float::NAN, 0.0), 2).len());
+    }
+
+    #[test]
+    fn unbounded_queries_cover_every_cell() {
+        let points = scattered(50, 100.0);
+        let grid = grid(&points);
+
+        let everywhere = Aabb::new(p(Float::NEG_INFINITY, -1e300), p(Float::INFINITY, 1e300));
+        assert_eq!(50, grid.query_aabb(&everywhere).len());
+        assert_eq!(50, grid.query_radius(p(0.0, 0.0), Length::in_meters(Float::INFINITY)).len());
+    }
+
+    #[test]
+    fn remove_and_move() {
+        let points = scattered(100, 100.0);
+        let mut grid = grid(&points);
+        let mut tree = quadtree(&points);
+
+        assert_eq!(Some(7), grid.remove(SpatialId(7)));
+        assert_eq!(Some(7), tree.remove(SpatialId(7)));
+        assert_eq!(None, grid.remove(SpatialId(7)));
+        assert_eq!(None, tree.remove(SpatialId(7)));
+        assert_eq!(99, grid.len());
+        assert_eq!(99, tree.len());
+
+        assert!(grid.move_to(SpatialId(3), p(500.0, 500.0)));
+        assert!(tree.move_to(SpatialId(3), p(500.0, 500.0)));
+        assert!(!grid.move_to(SpatialId(7), p(0.0, 0.0)));
+
+        let far = Aabb::new(p(400.0, 400.0), p(600.0, 600.0));
+        assert_eq!(vec![(SpatialId(3), &3)], grid.query_aabb(&far));
+        assert_eq!(vec![(SpatialId(3), &3)], tree.query_aabb(&far));
+
+        let nearest = tree.nearest(p(501.0, 500.0), 1);
+        assert_eq!(3, *nearest[0].value);
+        assert_eq!(Length::in_meters(1.0), nearest[0].distance);
+    }
+
+    #[test]
+    fn quadtree_subdivides_and_collapses() {
+        let points = scattered(200, 100.0);
+        let mut tree = quadtree(&points);
+        assert!(tree.root.children.is_some());
+
+        for i in 0..200 {
+            tree.remove(SpatialId(i));
+        }
+
+        assert!(tree.is_empty());
+        assert!(tree.root.children.is_none());
+    }
+
+    #[test]
+    fn coincident_points_stop_splitting_at_min_cell_size() {
+        let mut tree = Quadtree::new(Aabb::new(p(0.0, 0.0), p(16.0, 16.0)), Length::in_meters(1.0));
+
+        for i in 0..50 {
+            tree.insert(p(3.0, 3.0), i);
+        }
+
+        assert_eq!(50, tree.query_radius(p(3.0, 3.0), Length::zero()).len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn quadtree_rejects_zero_min_cell_size() {
+        let _ = Quadtree::<usize>::new(Aabb::new(p(0.0, 0.0), p(16.0, 16.0)), Length::zero());
+    }
+
+    #[test]
+    fn coincident_points_stop_splitting_when_bounds_cannot_halve() {
+        // halving a cell two units wide at 1e16 m rounds the center onto a corner
+        let corner = p(1e16 + 2.0, 1e16 + 2.0);
+        let mut tree = Quadtree::new(Aabb::new(p(1e16, 1e16), corner), Length::in_meters(Float::MIN_POSITIVE));
+
+        for i in 0..20 {
+            tree.insert(corner, i);
+        }
+
+        assert_eq!(20, tree.query_radius(corner, Length::zero()).len());
+    }
+}
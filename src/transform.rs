@@ -0,0 +1,233 @@
+use std::ops::*;
+use std::marker::PhantomData;
+use crate::*;
+
+/// A 2D similarity transform from frame `A` into frame `B`: scale, then rotate, then translate.
+///
+/// With a scale of one this is a rigid transform (an isometry).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform2<A = Unframed, B = A> {
+    pub translation: Vector<Meters, B>,
+    pub rotation: Angle,
+    pub scale: Float,
+    frames: PhantomData<(A, B)>,
+}
+
+impl<A: Frame, B: Frame> Default for Transform2<A, B> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<A: Frame, B: Frame> Transform2<A, B> {
+    /// Maps each value in `A` to the value with the same components in `B`.
+    #[inline]
+    pub const fn identity() -> Self {
+        Transform2 {
+            translation: Vector::zero(),
+            rotation: Angle::zero(),
+            scale: 1.0,
+            frames: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn isometry(translation: Vector<Meters, B>, rotation: Angle) -> Self {
+        Self::similarity(translation, rotation, 1.0)
+    }
+
+    #[inline]
+    pub fn similarity(translation: Vector<Meters, B>, rotation: Angle, scale: Float) -> Self {
+        Transform2 { translation, rotation, scale, frames: PhantomData }
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector<Meters, B>) -> Self {
+        Self::isometry(translation, Angle::zero())
+    }
+
+    #[inline]
+    pub fn from_rotation(rotation: Angle) -> Self {
+        Self::isometry(Vector::zero(), rotation)
+    }
+
+    #[inline]
+    pub fn from_scale(scale: Float) -> Self {
+        Self::similarity(Vector::zero(), Angle::zero(), scale)
+    }
+
+    /// Applies the full transform, including the translation.
+    #[inline]
+    pub fn transform_position(&self, position: Vector<Meters, A>) -> Vector<Meters, B> {
+        self.transform_vector(position) + self.translation
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: Point<Meters, A>) -> Point<Meters, B> {
+        Point::from_vector(self.transform_position(point.to_vector()))
+    }
+
+    /// Applies the rotation and scale but not the translation, for quantities
+    /// such as velocity and acceleration that are not affected by a change of
+    /// origin. The result is multiplied by the scale, so a velocity measured in
+    /// a frame scaled by 2 is twice as fast.
+    #[inline]
+    pub fn transform_vector<T: Unit>(&self, vector: Vector<T, A>) -> Vector<T, B> {
+        vector.rotate_cw(self.rotation).into_frame() * self.scale
+    }
+
+    /// Returns `None` if the scale is zero.
+    #[inline]
+    pub fn inverse(&self) -> Option<Transform2<B, A>> {
+        if self.scale == 0.0 {
+            return None;
+        }
+
+        let scale = 1.0 / self.scale;
+        let rotation = -self.rotation;
+        let translation = -self.translation.into_frame().rotate_cw(rotation) * scale;
+
+        Some(Transform2::similarity(translation, rotation, scale))
+    }
+
+    /// Returns the transform equivalent to applying `self` and then `next`.
+    #[inline]
+    pub fn then<C: Frame>(&self, next: &Transform2<B, C>) -> Transform2<A, C> {
+        *next * *self
+    }
+}
+
+/// `a * b` applies `b` first, then `a`.
+impl<A: Frame, B: Frame, C: Frame> Mul<Transform2<A, B>> for Transform2<B, C> {
+    type Output = Transform2<A, C>;
+    #[inline]
+    fn mul(self, rhs: Transform2<A, B>) -> Transform2<A, C> {
+        Transform2::similarity(
+            self.transform_position(rhs.translation),
+            self.rotation + rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl<A: Frame> MulAssign for Transform2<A> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_position_eq(expected: Position, actual: Position) {
+        assert_approx_eq!(expected.x.value, actual.x.value);
+        assert_approx_eq!(expected.y.value, actual.y.value);
+    }
+
+    fn sample() -> Transform2 {
+        Transform2::similarity(Position::in_meters(3.0, -2.0), Angle::in_degrees(30.0), 2.0)
+    }
+
+    #[test]
+    fn identity_leaves_position_unchanged() {
+        let position = Position::in_meters(1.5, 2.5);
+
+        assert_eq!(position, Transform2::identity().transform_position(position));
+    }
+
+    #[test]
+    fn translation_moves_position() {
+        let transform = Transform2::from_translation(Position::in_meters(1.0, 2.0));
+
+        assert_eq!(Position::in_meters(4.0, 6.0), transform.transform_position(Position::in_meters(3.0, 4.0)));
+    }
+
+    #[test]
+    fn rotation_matches_vector_rotation() {
+        let angle = Angle::in_degrees(90.0);
+        let position = Position::in_meters(1.0, 0.0);
+
+        let transform = Transform2::from_rotation(angle);
+
+        assert_position_eq(position.rotate_cw(angle), transform.transform_position(position));
+    }
+
+    #[test]
+    fn point_matches_position() {
+        let transform = sample();
+        let position = Position::in_meters(1.0, -4.0);
+
+        assert_eq!(transform.transform_position(position), transform.transform_point(Location::from_vector(position)).to_vector());
+    }
+
+    #[test]
+    fn velocity_is_not_translated() {
+        let transform = Transform2::isometry(Position::in_meters(10.0, 10.0), Angle::in_degrees(90.0));
+        let velocity = Velocity::in_meters_per_second(2.0, 0.0);
+
+        let result: Velocity = transform.transform_vector(velocity);
+
+        assert_approx_eq!(2.0, result.magnitude().value);
+    }
+
+    #[test]
+    fn velocity_is_rotated_and_scaled() {
+        let transform = Transform2::similarity(Position::in_meters(10.0, 10.0), Angle::in_degrees(90.0), 2.0);
+        let velocity = Velocity::in_meters_per_second(3.0, 0.0);
+
+        let result: Velocity = transform.transform_vector(velocity);
+        let expected = velocity.rotate_cw(Angle::in_degrees(90.0)) * 2.0;
+
+        assert_approx_eq!(expected.x.value, result.x.value);
+        assert_approx_eq!(expected.y.value, result.y.value);
+        assert_approx_eq!(6.0, result.magnitude().value);
+    }
+
+    #[test]
+    fn composition_matches_sequential_application() {
+        let parent = sample();
+        let child = Transform2::isometry(Position::in_meters(-1.0, 4.0), Angle::in_degrees(-75.0));
+        let position = Position::in_meters(0.5, 0.25);
+
+        let sequential = parent.transform_position(child.transform_position(position));
+
+        assert_position_eq(sequential, (parent * child).transform_position(position));
+        assert_position_eq(sequential, child.then(&parent).transform_position(position));
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let transform = sample();
+        let inverse = transform.inverse().unwrap();
+        let position = Position::in_meters(7.0, -3.0);
+
+        assert_position_eq(position, inverse.transform_position(transform.transform_position(position)));
+
+        let identity = transform * inverse;
+        assert_position_eq(Position::zero(), identity.translation);
+        assert_approx_eq!(0.0, identity.rotation.value);
+        assert_approx_eq!(1.0, identity.scale);
+    }
+
+    #[test]
+    fn inverse_of_zero_scale_is_none() {
+        assert_eq!(None, Transform2::<Unframed>::from_scale(0.0).inverse());
+    }
+
+    #[test]
+    fn transforms_between_frames() {
+        let ship_to_world = Transform2::<Local, World>::from_translation(Vector::in_meters(10.0, 0.0));
+        let world_to_screen = Transform2::<World, Screen>::from_scale(2.0);
+
+        let local = Vector::<Meters, Local>::in_meters(1.0, 2.0);
+        let world: Vector<Meters, World> = ship_to_world.transform_position(local);
+        let screen: Vector<Meters, Screen> = ship_to_world.then(&world_to_screen).transform_position(local);
+
+        assert_eq!(Vector::in_meters(11.0, 2.0), world);
+        assert_eq!(Vector::in_meters(22.0, 4.0), screen);
+        assert_eq!(local, ship_to_world.inverse().unwrap().transform_position(world));
+    }
+}
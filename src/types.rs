@@ -8,6 +8,8 @@ pub type Mass = Scalar<Kilograms>;
 
 pub type Length = Scalar<Meters>;
 pub type Position = Vector<Meters>;
+pub type Displacement = Vector<Meters>;
+pub type Location = Point<Meters>;
 
 pub type Speed = Scalar<MetersPerSecond>;
 pub type Velocity = Vector<MetersPerSecond>;
@@ -15,9 +17,15 @@ pub type Velocity = Vector<MetersPerSecond>;
 pub type AccelScalar = Scalar<MetersPerSecondSquared>;
 pub type Acceleration = Vector<MetersPerSecondSquared>;
 
+pub type ImpulseScalar = Scalar<KilogramMetersPerSecond>;
+pub type Impulse = Vector<KilogramMetersPerSecond>;
+pub type Momentum = Vector<KilogramMetersPerSecond>;
+
 pub type Temperature = Scalar<Kelvin>;
 
 pub type Force = Scalar<Newtons>;
+pub type ForceVector = Vector<Newtons>;
+pub type Pressure = Scalar<Pascals>;
 
 pub type Energy = Scalar<Joules>;
 
@@ -25,13 +33,22 @@ pub type EnergyDensity = Scalar<JoulesPerKilogram>;
 pub type EnergyRate = Scalar<JoulesPerSecond>;
 pub type MassRate = Scalar<KilogramsPerSecond>;
 
+pub type ExhaustVelocity = Scalar<MetersPerSecond>;
+pub type SpecificImpulse = Scalar<Seconds>;
+
 pub type Area = Scalar<MetersSquared>;
 pub type Volume = Scalar<MetersCubed>;
 pub type Density = Scalar<KilogramsPerMeterCubed>;
+pub type ArealDensity = Scalar<KilogramsPerMeterSquared>;
+pub type MomentOfInertia = Scalar<KilogramMetersSquared>;
+
+pub type GravitationalParameter = Scalar<MetersCubedPerSecondSquared>;
+pub type SpecificAngularMomentum = Scalar<MetersSquaredPerSecond>;
 
 pub type Resolution = Vector<Pixels>;
 pub type ResolutionScalar = Scalar<Pixels>;
 pub type Scale = Scalar<MetersPerPixel>;
 
 pub type Angle = Scalar<Radians>;
-pub type AngluarSpeed = Scalar<RadiansPerSecond>;
\ No newline at end of file
+pub type AngluarSpeed = Scalar<RadiansPerSecond>;
+pub type AngularMomentum = Scalar<KilogramMetersSquaredPerSecond>;
@@ -1,6 +1,6 @@
 use crate::Float;
 use crate::scalars::Scalar;
-use crate::vectors::Vector;
+use crate::vectors::{Vector, Vector3};
 
 pub trait Unit : 'static + Send + Sync + Copy + PartialEq + Default {
     #[inline]
@@ -23,12 +23,33 @@ pub trait Units {
     fn get_vector(x: Float, y: Float) -> Vector<Self::Output> {
         Vector::from((x, y))
     }
+
+    #[inline]
+    fn get_vector3(x: Float, y: Float, z: Float) -> Vector3<Self::Output> {
+        Vector3::from((x, y, z))
+    }
 }
 
 impl<T: Unit> Units for T {
     type Output = T;
 }
 
+/// The unit whose product with `Self` is dimensionless.
+pub trait Reciprocal: Unit {
+    type Output: Unit;
+}
+
+impl Reciprocal for Float {
+    type Output = Float;
+}
+
+/// The square root of a squared quantity, such as `Area` to `Length`.
+pub trait Sqrt {
+    type Output;
+
+    fn sqrt(self) -> Self::Output;
+}
+
 macro_rules! define_unit {
     ($unit:ident) => (
         #[derive(Debug, Default, PartialEq, Copy, Clone, PartialOrd)]
@@ -65,7 +86,30 @@ define_unit!(JoulesPerKilogram, "J/kg");
 define_unit!(JoulesPerSecond, "J/s");
 define_unit!(KilogramsPerSecond, "kg/s");
 define_unit!(KilogramsPerMeterCubed, "kg/m³");
+define_unit!(KilogramsPerMeterSquared, "kg/m²");
+define_unit!(KilogramMetersSquared, "kg·m²");
+define_unit!(MetersToTheFourth, "m⁴");
 define_unit!(Pixels, "px");
 define_unit!(MetersPerPixel, "m/px");
 define_unit!(Radians, "rad");
 define_unit!(RadiansPerSecond, "rad/s");
+define_unit!(PerMeter, "1/m");
+define_unit!(PerSecond, "1/s");
+define_unit!(PerKilogram, "1/kg");
+define_unit!(KilogramMetersPerSecond, "kg·m/s");
+define_unit!(KilogramMetersSquaredPerSecond, "kg·m²/s");
+define_unit!(MetersSquaredPerSecond, "m²/s");
+define_unit!(MetersSquaredPerSecondCubed, "m²/s³");
+define_unit!(MetersCubedPerSecondSquared, "m³/s²");
+define_unit!(MetersCubedPerKilogramSecondSquared, "m³/(kg·s²)");
+define_unit!(NewtonMetersSquared, "N·m²");
+define_unit!(KelvinSquared, "K²");
+define_unit!(KelvinToTheFourth, "K⁴");
+define_unit!(JoulesPerKelvin, "J/K");
+define_unit!(JoulesPerSecondMeterSquared, "J/(s·m²)");
+define_unit!(JoulesPerSecondMeterSquaredKelvinToTheFourth, "J/(s·m²·K⁴)");
+define_unit!(JouleSeconds, "J·s");
+define_unit!(Moles, "mol");
+define_unit!(PerMole, "1/mol");
+define_unit!(Pascals, "Pa");
+define_unit!(JoulesPerKilogramKelvin, "J/(kg·K)");
@@ -1,23 +1,33 @@
 use std::ops::*;
+use std::marker::PhantomData;
 use std::fmt::{Display, Formatter, Result, LowerExp};
 use crate::*;
-use crate::types::UnitVector;
 
+/// A 2D vector with units `T`, expressed in the coordinate frame `F`.
 #[derive(Debug, Default, PartialEq, Copy, Clone)]
-pub struct Vector<T> {
+pub struct Vector<T, F = Unframed> {
     pub x: Scalar<T>,
     pub y: Scalar<T>,
+    frame: PhantomData<F>,
 }
 
-impl<T> Vector<T> {
+impl<T, F: Frame> Vector<T, F> {
     #[inline]
-    fn new<U: Into<Scalar<T>>>(x: U, y: U) -> Vector<T> {
-        Vector { x: x.into(), y: y.into() }
+    fn new<U: Into<Scalar<T>>>(x: U, y: U) -> Vector<T, F> {
+        Vector { x: x.into(), y: y.into(), frame: PhantomData }
     }
 
     #[inline]
-    pub const fn zero() -> Vector<T> {
-        Vector { x: Scalar::zero(), y: Scalar::zero() }
+    pub const fn zero() -> Vector<T, F> {
+        Vector { x: Scalar::zero(), y: Scalar::zero(), frame: PhantomData }
+    }
+
+    /// Relabels the vector as belonging to frame `G` without changing its components.
+    ///
+    /// This is unchecked, so outside the crate frames change only through a `Transform2`.
+    #[inline]
+    pub(crate) fn into_frame<G: Frame>(self) -> Vector<T, G> {
+        Vector::new(self.x, self.y)
     }
 
     #[inline]
@@ -29,11 +39,32 @@ impl<T> Vector<T> {
     pub fn magnitude_squared(&self) -> Float {
         self.x.value.powi(2) + self.y.value.powi(2)
     }
+
+    #[inline]
+    pub fn dot<U, V>(self, rhs: Vector<U, F>) -> Scalar<V>
+        where Scalar<T>: Mul<Scalar<U>, Output=Scalar<V>>
+    {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// The z-component of the 3D cross product, positive when `rhs` is counter-clockwise from `self`.
+    #[inline]
+    pub fn cross<U, V>(self, rhs: Vector<U, F>) -> Scalar<V>
+        where Scalar<T>: Mul<Scalar<U>, Output=Scalar<V>>
+    {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// The vector rotated a quarter turn counter-clockwise.
+    #[inline]
+    pub fn perpendicular(self) -> Self {
+        Vector::new(-self.y, self.x)
+    }
 }
 
-impl<T: Unit> Vector<T> {
+impl<T: Unit, F: Frame> Vector<T, F> {
     #[inline]
-    pub fn unit_vector(self) -> Option<UnitVector> {
+    pub fn unit_vector(self) -> Option<Vector<Float, F>> {
         if self == Self::zero() {
             None
         } else {
@@ -79,35 +110,42 @@ impl<T: Unit> Vector<T> {
     }
 }
 
-impl Vector<Meters> {
+impl<F: Frame> Vector<Meters, F> {
+    #[inline]
+    pub fn in_meters<T: Into<Scalar<Meters>>>(x: T, y: T) -> Self {
+        Vector::new(x, y)
+    }
+}
+
+impl<F: Frame> Vector<MetersPerSecond, F> {
     #[inline]
-    pub fn in_meters<T: Into<Scalar<Meters>>>(x: T, y: T) -> Position {
+    pub fn in_meters_per_second<T: Into<Scalar<MetersPerSecond>>>(x: T, y: T) -> Self {
         Vector::new(x, y)
     }
 }
 
-impl Vector<MetersPerSecond> {
+impl<F: Frame> Vector<MetersPerSecondSquared, F> {
     #[inline]
-    pub fn in_meters_per_second<T: Into<Scalar<MetersPerSecond>>>(x: T, y: T) -> Velocity {
+    pub fn in_meters_per_second_squared<T: Into<Scalar<MetersPerSecondSquared>>>(x: T, y: T) -> Self {
         Vector::new(x, y)
     }
 }
 
-impl Vector<MetersPerSecondSquared> {
+impl<F: Frame> Vector<KilogramMetersPerSecond, F> {
     #[inline]
-    pub fn in_meters_per_second_squared<T: Into<Scalar<MetersPerSecondSquared>>>(x: T, y: T) -> Acceleration {
+    pub fn in_kilogram_meters_per_second<T: Into<Scalar<KilogramMetersPerSecond>>>(x: T, y: T) -> Self {
         Vector::new(x, y)
     }
 }
 
-impl Vector<Pixels> {
+impl<F: Frame> Vector<Pixels, F> {
     #[inline]
-    pub fn in_pixels<T: Into<Scalar<Pixels>>>(x: T, y: T) -> Resolution {
+    pub fn in_pixels<T: Into<Scalar<Pixels>>>(x: T, y: T) -> Self {
         Vector::new(x, y)
     }
 }
 
-impl<T: Unit> Display for Vector<T> {
+impl<T: Unit, F: Frame> Display for Vector<T, F> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result {
         let precision = f.precision().unwrap_or(2);
@@ -115,7 +153,7 @@ impl<T: Unit> Display for Vector<T> {
     }
 }
 
-impl<T: Unit> LowerExp for Vector<T> {
+impl<T: Unit, F: Frame> LowerExp for Vector<T, F> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> Result {
         let precision = f.precision().unwrap_or(2);
@@ -123,14 +161,14 @@ impl<T: Unit> LowerExp for Vector<T> {
     }
 }
 
-impl<T, U: Into<Scalar<T>>> From<(U, U)> for Vector<T> {
+impl<T, F: Frame, U: Into<Scalar<T>>> From<(U, U)> for Vector<T, F> {
     #[inline]
-    fn from((x, y): (U, U)) -> Vector<T> {
+    fn from((x, y): (U, U)) -> Vector<T, F> {
         Vector::new(x, y)
     }
 }
 
-impl<T> Neg for Vector<T> {
+impl<T, F: Frame> Neg for Vector<T, F> {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self::Output {
@@ -138,7 +176,7 @@ impl<T> Neg for Vector<T> {
     }
 }
 
-impl<T> Add for Vector<T> {
+impl<T, F: Frame> Add for Vector<T, F> {
     type Output = Self;
 
     #[inline]
@@ -147,7 +185,7 @@ impl<T> Add for Vector<T> {
     }
 }
 
-impl<T> AddAssign for Vector<T> {
+impl<T, F: Frame> AddAssign for Vector<T, F> {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
@@ -155,7 +193,7 @@ impl<T> AddAssign for Vector<T> {
     }
 }
 
-impl<T> Sub for Vector<T> {
+impl<T, F: Frame> Sub for Vector<T, F> {
     type Output = Self;
     #[inline]
     fn sub(self, rhs: Self) -> Self {
@@ -163,7 +201,7 @@ impl<T> Sub for Vector<T> {
     }
 }
 
-impl<T> SubAssign for Vector<T> {
+impl<T, F: Frame> SubAssign for Vector<T, F> {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
@@ -171,15 +209,15 @@ impl<T> SubAssign for Vector<T> {
     }
 }
 
-impl<T> Mul<Vector<T>> for Float {
-    type Output = Vector<T>;
+impl<T, F: Frame> Mul<Vector<T, F>> for Float {
+    type Output = Vector<T, F>;
     #[inline]
-    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+    fn mul(self, rhs: Vector<T, F>) -> Vector<T, F> {
         Vector::new(self * rhs.x, self * rhs.y)
     }
 }
 
-impl<T> Mul<Float> for Vector<T> {
+impl<T, F: Frame> Mul<Float> for Vector<T, F> {
     type Output = Self;
     #[inline]
     fn mul(self, rhs: Float) -> Self {
@@ -187,7 +225,7 @@ impl<T> Mul<Float> for Vector<T> {
     }
 }
 
-impl<T> MulAssign<Float> for Vector<T> {
+impl<T, F: Frame> MulAssign<Float> for Vector<T, F> {
     #[inline]
     fn mul_assign(&mut self, rhs: Float) {
         self.x *= rhs;
@@ -195,7 +233,7 @@ impl<T> MulAssign<Float> for Vector<T> {
     }
 }
 
-impl<T> Div<Float> for Vector<T> {
+impl<T, F: Frame> Div<Float> for Vector<T, F> {
     type Output = Self;
     #[inline]
     fn div(self, rhs: Float) -> Self {
@@ -203,15 +241,15 @@ impl<T> Div<Float> for Vector<T> {
     }
 }
 
-impl<T> Div<Scalar<T>> for Vector<T> {
-    type Output = Vector<Float>;
+impl<T, F: Frame> Div<Scalar<T>> for Vector<T, F> {
+    type Output = Vector<Float, F>;
     #[inline]
     fn div(self, rhs: Scalar<T>) -> Self::Output {
         Vector::from((self.x.value / rhs.value, self.y.value / rhs.value))
     }
 }
 
-impl<T> DivAssign<Float> for Vector<T> {
+impl<T, F: Frame> DivAssign<Float> for Vector<T, F> {
     #[inline]
     fn div_assign(&mut self, rhs: Float) {
         self.x /= rhs;
@@ -219,22 +257,132 @@ impl<T> DivAssign<Float> for Vector<T> {
     }
 }
 
-impl<T> Mul<UnitVector> for Scalar<T> {
-    type Output = Vector<T>;
+impl<T, F: Frame> Mul<Vector<Float, F>> for Scalar<T> {
+    type Output = Vector<T, F>;
     #[inline]
-    fn mul(self, rhs: UnitVector) -> Self::Output {
+    fn mul(self, rhs: Vector<Float, F>) -> Self::Output {
         Vector::new(self.value * rhs.x.value, self.value * rhs.y.value)
     }
 }
 
-impl<T> Mul<Scalar<T>> for UnitVector {
-    type Output = Vector<T>;
+impl<T, F: Frame> Mul<Scalar<T>> for Vector<Float, F> {
+    type Output = Vector<T, F>;
     #[inline]
     fn mul(self, rhs: Scalar<T>) -> Self::Output {
         rhs * self
     }
 }
 
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Vector3<T> {
+    pub x: Scalar<T>,
+    pub y: Scalar<T>,
+    pub z: Scalar<T>,
+}
+
+impl<T> Vector3<T> {
+    #[inline]
+    fn new<U: Into<Scalar<T>>>(x: U, y: U, z: U) -> Vector3<T> {
+        Vector3 { x: x.into(), y: y.into(), z: z.into() }
+    }
+
+    #[inline]
+    pub const fn zero() -> Vector3<T> {
+        Vector3 { x: Scalar::zero(), y: Scalar::zero(), z: Scalar::zero() }
+    }
+
+    #[inline]
+    pub fn magnitude(&self) -> Scalar<T> {
+        self.magnitude_squared().sqrt().into()
+    }
+
+    #[inline]
+    pub fn magnitude_squared(&self) -> Float {
+        self.x.value.powi(2) + self.y.value.powi(2) + self.z.value.powi(2)
+    }
+}
+
+impl<T: Unit> Display for Vector3<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "({:.p$}, {:.p$}, {:.p$})", self.x, self.y, self.z, p=precision)
+    }
+}
+
+impl<T, U: Into<Scalar<T>>> From<(U, U, U)> for Vector3<T> {
+    #[inline]
+    fn from((x, y, z): (U, U, U)) -> Vector3<T> {
+        Vector3::new(x, y, z)
+    }
+}
+
+impl<T> Neg for Vector3<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl<T> Add for Vector3<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T> AddAssign for Vector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T> Sub for Vector3<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> SubAssign for Vector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> Mul<Vector3<T>> for Float {
+    type Output = Vector3<T>;
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        rhs * self
+    }
+}
+
+impl<T> Mul<Float> for Vector3<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Float) -> Self {
+        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> Div<Float> for Vector3<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Float) -> Self {
+        Vector3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -399,6 +547,37 @@ mod tests {
         assert_eq!(25.0, v.magnitude_squared());
     }
 
+    #[test]
+    fn dot() {
+        let v1 = Position::new(2.0, 3.0);
+        let v2 = Position::new(4.0, -1.0);
+
+        assert_eq!(Area::in_meters_squared(5.0), v1.dot(v2));
+    }
+
+    #[test]
+    fn cross() {
+        let v1 = Position::new(1.0, 0.0);
+        let v2 = Position::new(0.0, 2.0);
+
+        assert_eq!(Area::in_meters_squared(2.0), v1.cross(v2));
+        assert_eq!(Area::in_meters_squared(-2.0), v2.cross(v1));
+    }
+
+    #[test]
+    fn dot_converts_units() {
+        let velocity = Velocity::in_meters_per_second(1.0, 2.0);
+        let time = Scalar::<Seconds>::from(3.0);
+        let direction = UnitVector::new(0.0, 1.0);
+
+        assert_eq!(Length::in_meters(6.0), direction.dot(velocity) * time);
+    }
+
+    #[test]
+    fn perpendicular() {
+        assert_eq!(Position::new(-2.0, 1.0), Position::new(1.0, 2.0).perpendicular());
+    }
+
     #[test]
     fn magnitude() {
         let v = Position::new(3.0, 4.0);
@@ -482,4 +661,25 @@ mod tests {
 
         assert_eq!(pos.get_angle(), Some(angle));
     }
+
+    #[test]
+    fn vector3_add_and_sub() {
+        let v1 = Meters::get_vector3(1.0, 2.0, 3.0);
+        let v2 = Meters::get_vector3(4.0, 5.0, 6.0);
+
+        assert_eq!(Meters::get_vector3(5.0, 7.0, 9.0), v1 + v2);
+        assert_eq!(Meters::get_vector3(-3.0, -3.0, -3.0), v1 - v2);
+    }
+
+    #[test]
+    fn vector3_magnitude() {
+        let v = Meters::get_vector3(2.0, 3.0, 6.0);
+
+        assert_eq!(7.0, v.magnitude().value);
+    }
+
+    #[test]
+    fn vector3_display() {
+        assert_eq!("(1.00 m, 2.00 m, 3.00 m)", Meters::get_vector3(1.0, 2.0, 3.0).to_string());
+    }
 }
\ No newline at end of file